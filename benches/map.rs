@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+type ArcKString = kstring::KStringBase<kstring::backend::ArcStr>;
+type BoxedKString = kstring::KStringBase<kstring::backend::BoxedStr>;
+
+#[cfg(not(feature = "unstable_bench_subset"))]
+// Inline, small heap and large heap strings
+pub static LENS: &[usize] = &[8, 64, 1024, 4096];
+
+#[cfg(feature = "unstable_bench_subset")]
+pub static LENS: &[usize] = &[64, 4096];
+
+const KEYS: usize = 64;
+
+/// `KEYS` distinct keys of `len` bytes, only differing in their last bytes
+fn keys(len: usize) -> Vec<String> {
+    (0..KEYS).map(|i| format!("{i:0>len$}")).collect()
+}
+
+// Compares a string with a clone (which shares its storage when the backend does) and with an
+// equal string that has its own storage.
+fn bench_eq(c: &mut Criterion) {
+    let mut group = c.benchmark_group("eq");
+    for &len in LENS {
+        let key = keys(len).pop().unwrap();
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_with_input(BenchmarkId::new("String", len), &len, |b, _| {
+            let uut = key.clone();
+            let other = std::hint::black_box(uut.clone());
+            b.iter(|| uut == other);
+        });
+        group.bench_with_input(
+            BenchmarkId::new("KString<Box>::clone", len),
+            &len,
+            |b, _| {
+                let uut = BoxedKString::from_ref(&key);
+                let other = std::hint::black_box(uut.clone());
+                b.iter(|| uut == other);
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("KString<Arc>::clone", len),
+            &len,
+            |b, _| {
+                let uut = ArcKString::from_ref(&key);
+                let other = std::hint::black_box(uut.clone());
+                b.iter(|| uut == other);
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("KString<Arc>::from_ref", len),
+            &len,
+            |b, _| {
+                let uut = ArcKString::from_ref(&key);
+                let other = std::hint::black_box(ArcKString::from_ref(&key));
+                b.iter(|| uut == other);
+            },
+        );
+    }
+    group.finish();
+}
+
+// Looks up a key in a map, where the key is a clone of the one stored in the map (sharing its
+// storage when the backend does) or an equal key with its own storage.
+//
+// Hashing still reads every byte, as the hash must match `str`'s for `Borrow<str>` lookups.
+fn bench_map_get(c: &mut Criterion) {
+    let mut group = c.benchmark_group("map_get");
+    for &len in LENS {
+        let keys = keys(len);
+        let key = keys.last().unwrap();
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_with_input(BenchmarkId::new("String", len), &len, |b, _| {
+            let map: HashMap<_, _> = keys.iter().cloned().zip(0..).collect();
+            let lookup = std::hint::black_box(key.clone());
+            b.iter(|| map.get(&lookup));
+        });
+        group.bench_with_input(
+            BenchmarkId::new("KString<Box>::clone", len),
+            &len,
+            |b, _| {
+                let map: HashMap<_, _> = keys
+                    .iter()
+                    .map(|k| BoxedKString::from_ref(k))
+                    .zip(0..)
+                    .collect();
+                let lookup = std::hint::black_box(map.keys().find(|k| *k == key).unwrap().clone());
+                b.iter(|| map.get(&lookup));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("KString<Arc>::clone", len),
+            &len,
+            |b, _| {
+                let map: HashMap<_, _> = keys
+                    .iter()
+                    .map(|k| ArcKString::from_ref(k))
+                    .zip(0..)
+                    .collect();
+                let lookup = std::hint::black_box(map.keys().find(|k| *k == key).unwrap().clone());
+                b.iter(|| map.get(&lookup));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("KString<Arc>::from_ref", len),
+            &len,
+            |b, _| {
+                let map: HashMap<_, _> = keys
+                    .iter()
+                    .map(|k| ArcKString::from_ref(k))
+                    .zip(0..)
+                    .collect();
+                let lookup = std::hint::black_box(ArcKString::from_ref(key));
+                b.iter(|| map.get(&lookup));
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_eq, bench_map_get);
+criterion_main!(benches);
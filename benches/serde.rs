@@ -0,0 +1,77 @@
+use bincode1::Options as _;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+#[cfg(not(feature = "unstable_bench_subset"))]
+pub static FIXTURES: &[&str] = &[
+    // Empty handling
+    "",
+    // Barely used
+    "1",
+    // kstring's max small-string size
+    "123456789012345",
+    // Boundary conditions for most small-string optimizations
+    "1234567890123456789012",
+    "12345678901234567890123",
+    "123456789012345678901234",
+    "1234567890123456789012345",
+    // Small heap
+    "1234567890123456789012345678901234567890123456789012345678901234",
+];
+
+#[cfg(feature = "unstable_bench_subset")]
+pub static FIXTURES: &[&str] = &[
+    "0123456789",
+    "01234567890123456789012345678901234567890123456789012345678901234567890123456789",
+];
+
+fn options() -> impl bincode1::Options {
+    bincode1::options()
+        .with_fixint_encoding()
+        .allow_trailing_bytes()
+}
+
+// `bincode` allocates a `String` when asked for one but lends out its buffer when asked for a
+// `str`, showing the cost of the temporary allocation.
+fn bench_deserialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deserialize");
+    for fixture in FIXTURES {
+        let len = fixture.len();
+        let bytes = options().serialize(fixture).unwrap();
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_with_input(BenchmarkId::new("String", len), &len, |b, _| {
+            let bytes = std::hint::black_box(bytes.as_slice());
+            b.iter(|| options().deserialize::<String>(bytes).unwrap());
+        });
+        group.bench_with_input(
+            BenchmarkId::new("KString::from_string", len),
+            &len,
+            |b, _| {
+                let bytes = std::hint::black_box(bytes.as_slice());
+                b.iter(|| {
+                    kstring::KString::from_string(options().deserialize::<String>(bytes).unwrap())
+                });
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("KString", len), &len, |b, _| {
+            let bytes = std::hint::black_box(bytes.as_slice());
+            b.iter(|| options().deserialize::<kstring::KString>(bytes).unwrap());
+        });
+        group.bench_with_input(
+            BenchmarkId::new("KString::deserialize_in_place", len),
+            &len,
+            |b, _| {
+                let bytes = std::hint::black_box(bytes.as_slice());
+                let mut place = kstring::KString::new();
+                b.iter(|| {
+                    let mut deserializer = bincode1::Deserializer::from_slice(bytes, options());
+                    serde::Deserialize::deserialize_in_place(&mut deserializer, &mut place)
+                        .unwrap();
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_deserialize);
+criterion_main!(benches);
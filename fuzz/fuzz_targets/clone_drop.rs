@@ -0,0 +1,132 @@
+#![no_main]
+
+use kstring::backend::HeapStr;
+use kstring::KStringBase;
+use kstring::KStringCowBase;
+use kstring_fuzz::check;
+use kstring_fuzz::check_str;
+use kstring_fuzz::pick_static;
+use kstring_fuzz::Repr;
+use kstring_fuzz::Target;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, arbitrary::Arbitrary)]
+enum Op<'a> {
+    PushStatic(u8),
+    PushRef(&'a str),
+    PushHeap(&'a str),
+    PushBorrowed(&'a str),
+    Clone(u8),
+    CloneFrom(u8, u8),
+    Drop(u8),
+    Take(u8),
+    Swap(u8, u8),
+    Compare(u8, u8),
+}
+
+#[derive(Debug, arbitrary::Arbitrary)]
+struct Input<'a> {
+    repr: Repr,
+    ops: Vec<Op<'a>>,
+}
+
+/// Strings under test, alongside the `String` each is expected to equal
+struct Pool<'a, B, const N: usize> {
+    actual: Vec<KStringCowBase<'a, B, N>>,
+    expected: Vec<String>,
+}
+
+impl<'a, B: HeapStr, const N: usize> Pool<'a, B, N> {
+    fn index(&self, i: u8) -> Option<usize> {
+        (!self.actual.is_empty()).then(|| usize::from(i) % self.actual.len())
+    }
+
+    fn push(&mut self, actual: KStringCowBase<'a, B, N>, expected: &str) {
+        self.actual.push(actual);
+        self.expected.push(expected.to_owned());
+    }
+
+    fn apply(&mut self, op: Op<'a>) {
+        match op {
+            Op::PushStatic(i) => {
+                self.push(KStringCowBase::from_static(pick_static(i)), pick_static(i));
+            }
+            Op::PushRef(s) => self.push(KStringBase::from_ref(s).into(), s),
+            Op::PushHeap(s) => self.push(KStringBase::from_heap(B::from_str(s)).into(), s),
+            Op::PushBorrowed(s) => self.push(KStringCowBase::from_ref(s), s),
+            Op::Clone(i) => {
+                if let Some(i) = self.index(i) {
+                    let clone = self.actual[i].clone();
+                    let owned = clone.clone().into_owned();
+                    check(&owned, &self.expected[i]);
+                    self.actual.push(clone);
+                    self.expected.push(self.expected[i].clone());
+                    self.actual.push(owned.into());
+                    self.expected.push(self.expected[i].clone());
+                }
+            }
+            Op::CloneFrom(a, b) => {
+                if let (Some(a), Some(b)) = (self.index(a), self.index(b)) {
+                    let source = self.actual[b].clone();
+                    self.actual[a].clone_from(&source);
+                    self.expected[a] = self.expected[b].clone();
+                }
+            }
+            Op::Drop(i) => {
+                if let Some(i) = self.index(i) {
+                    self.actual.swap_remove(i);
+                    self.expected.swap_remove(i);
+                }
+            }
+            Op::Take(i) => {
+                if let Some(i) = self.index(i) {
+                    let taken = std::mem::take(&mut self.actual[i]);
+                    let expected = std::mem::take(&mut self.expected[i]);
+                    check_str(&taken, &expected);
+                }
+            }
+            Op::Swap(a, b) => {
+                if let (Some(a), Some(b)) = (self.index(a), self.index(b)) {
+                    self.actual.swap(a, b);
+                    self.expected.swap(a, b);
+                }
+            }
+            Op::Compare(a, b) => {
+                if let (Some(a), Some(b)) = (self.index(a), self.index(b)) {
+                    assert_eq!(
+                        self.actual[a] == self.actual[b],
+                        self.expected[a] == self.expected[b]
+                    );
+                    assert_eq!(
+                        self.actual[a].cmp(&self.actual[b]),
+                        self.expected[a].cmp(&self.expected[b])
+                    );
+                }
+            }
+        }
+    }
+
+    fn check(&self) {
+        assert_eq!(self.actual.len(), self.expected.len());
+        for (actual, expected) in self.actual.iter().zip(&self.expected) {
+            check_str(actual, expected);
+        }
+    }
+}
+
+impl Target for Input<'_> {
+    fn run<B: HeapStr, const N: usize>(self) {
+        let mut pool = Pool::<B, N> {
+            actual: Vec::new(),
+            expected: Vec::new(),
+        };
+        for op in self.ops {
+            pool.apply(op);
+            pool.check();
+        }
+    }
+}
+
+fuzz_target!(|input: Input<'_>| {
+    input.repr.run(input);
+});
@@ -0,0 +1,261 @@
+#![no_main]
+
+use kstring::backend::HeapStr;
+use kstring::KStringBase;
+use kstring::KStringBuilder;
+use kstring::KStringCowBase;
+use kstring::KStringRef;
+use kstring::StackString;
+use kstring::StorageKind;
+use kstring_fuzz::check;
+use kstring_fuzz::check_inlined;
+use kstring_fuzz::check_str;
+use kstring_fuzz::pick_static;
+use kstring_fuzz::Repr;
+use kstring_fuzz::Target;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, arbitrary::Arbitrary)]
+enum Constructor<'a> {
+    New,
+    Default,
+    FromStatic(u8),
+    FromStaticInline(u8),
+    TryInline(&'a str),
+    FromHeap(&'a str),
+    FromBoxed(&'a str),
+    FromString(&'a str),
+    FromRef(&'a str),
+    FromFmt(&'a str, i64),
+    FromUtf8(&'a [u8]),
+    FromUtf8Lossy(&'a [u8]),
+    FromUtf8Unchecked(&'a str),
+    Parse(&'a str),
+    FromStdString(&'a str),
+    FromStdStringRef(&'a str),
+    FromBoxedStr(&'a str),
+    FromBoxedStrRef(&'a str),
+    FromStaticStr(u8),
+    Builder(Option<u8>, Vec<Push<'a>>),
+    Intern(&'a str),
+    CowFromStatic(u8),
+    CowFromBoxed(&'a str),
+    CowFromString(&'a str),
+    CowFromRef(&'a str),
+    CowParse(&'a str),
+    RefFromStatic(u8),
+    RefFromRef(&'a str),
+    StackTryNew(&'a str),
+    StackFromUtf8(&'a [u8]),
+    StackFromUtf8Lossy(&'a [u8]),
+}
+
+#[derive(Debug, arbitrary::Arbitrary)]
+enum Push<'a> {
+    Char(char),
+    Str(&'a str),
+}
+
+#[derive(Debug, arbitrary::Arbitrary)]
+struct Input<'a> {
+    repr: Repr,
+    constructors: Vec<Constructor<'a>>,
+}
+
+impl Target for Constructor<'_> {
+    fn run<B: HeapStr, const N: usize>(self) {
+        type K<B, const N: usize> = KStringBase<B, N>;
+        type Cow<'s, B, const N: usize> = KStringCowBase<'s, B, N>;
+
+        match self {
+            Self::New => check(&K::<B, N>::new(), ""),
+            Self::Default => check(&K::<B, N>::default(), ""),
+            Self::FromStatic(i) => {
+                let s = K::<B, N>::from_static(pick_static(i));
+                check(&s, pick_static(i));
+                assert_eq!(s.storage(), StorageKind::Static);
+            }
+            Self::FromStaticInline(i) => {
+                let expected = pick_static(i);
+                let s = K::<B, N>::from_static_inline(expected);
+                check(&s, expected);
+                let storage = if expected.len() <= N {
+                    StorageKind::Inline
+                } else {
+                    StorageKind::Static
+                };
+                assert_eq!(s.storage(), storage);
+            }
+            Self::TryInline(expected) => match K::<B, N>::try_inline(expected) {
+                Some(s) => check_inlined(&s, expected),
+                None => assert!(N < expected.len()),
+            },
+            Self::FromHeap(expected) => {
+                let s = K::<B, N>::from_heap(B::from_str(expected));
+                check(&s, expected);
+                assert_eq!(s.storage(), StorageKind::Heap);
+            }
+            Self::FromBoxed(expected) => {
+                let s = K::<B, N>::from_boxed(expected.into());
+                check(&s, expected);
+                assert_eq!(s.storage(), StorageKind::Heap);
+            }
+            Self::FromString(expected) => {
+                check_inlined(&K::<B, N>::from_string(expected.to_owned()), expected);
+            }
+            Self::FromRef(expected) => check_inlined(&K::<B, N>::from_ref(expected), expected),
+            Self::FromFmt(s, n) => {
+                let expected = format!("{s}{n}");
+                check_inlined(&K::<B, N>::from_fmt(format_args!("{s}{n}")), &expected);
+            }
+            Self::FromUtf8(bytes) => {
+                match (K::<B, N>::from_utf8(bytes), std::str::from_utf8(bytes)) {
+                    (Ok(s), Ok(expected)) => check_inlined(&s, expected),
+                    (Err(actual), Err(expected)) => assert_eq!(actual, expected),
+                    (actual, expected) => panic!("{actual:?} != {expected:?}"),
+                }
+            }
+            Self::FromUtf8Lossy(bytes) => {
+                let expected = String::from_utf8_lossy(bytes);
+                check_inlined(&K::<B, N>::from_utf8_lossy(bytes), &expected);
+            }
+            Self::FromUtf8Unchecked(expected) => {
+                let s = unsafe {
+                    // SAFETY: Bytes come from a `&str`
+                    K::<B, N>::from_utf8_unchecked(expected.as_bytes())
+                };
+                check_inlined(&s, expected);
+            }
+            Self::Parse(expected) => {
+                let s: K<B, N> = expected.parse().unwrap();
+                check_inlined(&s, expected);
+            }
+            Self::FromStdString(expected) => {
+                check_inlined(&K::<B, N>::from(expected.to_owned()), expected);
+            }
+            Self::FromStdStringRef(expected) => {
+                check_inlined(&K::<B, N>::from(&expected.to_owned()), expected);
+            }
+            Self::FromBoxedStr(expected) => {
+                check(&K::<B, N>::from(Box::<str>::from(expected)), expected);
+            }
+            Self::FromBoxedStrRef(expected) => {
+                check(&K::<B, N>::from(&Box::<str>::from(expected)), expected);
+            }
+            Self::FromStaticStr(i) => {
+                let s = K::<B, N>::from(pick_static(i));
+                check(&s, pick_static(i));
+                assert_eq!(s.storage(), StorageKind::Static);
+            }
+            Self::Builder(capacity, pushes) => {
+                let mut builder = match capacity {
+                    Some(capacity) => KStringBuilder::<B, N>::with_capacity(capacity.into()),
+                    None => KStringBuilder::<B, N>::new(),
+                };
+                let mut expected = String::new();
+                for push in pushes {
+                    match push {
+                        Push::Char(c) => {
+                            builder.push(c);
+                            expected.push(c);
+                        }
+                        Push::Str(s) => {
+                            builder.push_str(s);
+                            expected.push_str(s);
+                        }
+                    }
+                    assert_eq!(builder.as_str(), expected);
+                    assert_eq!(builder.len(), expected.len());
+                    assert_eq!(builder.is_empty(), expected.is_empty());
+                }
+                let clone = builder.clone();
+                check_inlined(&builder.finish(), &expected);
+                check_inlined(&K::<B, N>::from(clone), &expected);
+            }
+            Self::Intern(expected) => {
+                let global = kstring::intern::Interner::global();
+                let first = global.intern(expected);
+                let second = global.intern(expected);
+                check_inlined(&first, expected);
+                check_inlined(&second, expected);
+                drop((first, second));
+                global.purge();
+
+                kstring::intern::LocalInterner::with_thread_local(|local| {
+                    check_inlined(&local.intern(expected), expected);
+                    local.purge();
+                });
+            }
+            Self::CowFromStatic(i) => {
+                let s = Cow::<B, N>::from_static(pick_static(i));
+                check_str(&s, pick_static(i));
+                assert_eq!(s.storage(), StorageKind::Static);
+            }
+            Self::CowFromBoxed(expected) => {
+                let s = Cow::<B, N>::from_boxed(expected.into());
+                check_str(&s, expected);
+                assert_eq!(s.storage(), StorageKind::Heap);
+            }
+            Self::CowFromString(expected) => {
+                let s = Cow::<B, N>::from_string(expected.to_owned());
+                check_str(&s, expected);
+                assert_ne!(s.storage(), StorageKind::Borrowed);
+            }
+            Self::CowFromRef(expected) => {
+                let s = Cow::<B, N>::from_ref(expected);
+                check_str(&s, expected);
+                assert_eq!(s.storage(), StorageKind::Borrowed);
+            }
+            Self::CowParse(expected) => {
+                let s: Cow<'_, B, N> = expected.parse().unwrap();
+                check_str(&s, expected);
+            }
+            Self::RefFromStatic(i) => {
+                let s = KStringRef::from_static(pick_static(i));
+                check_str(&s, pick_static(i));
+                assert_eq!(s.storage(), StorageKind::Static);
+            }
+            Self::RefFromRef(expected) => {
+                let s = KStringRef::from_ref(expected);
+                check_str(&s, expected);
+                assert_eq!(s.storage(), StorageKind::Borrowed);
+            }
+            Self::StackTryNew(expected) => match StackString::<N>::try_new(expected) {
+                Some(s) => check_str(&s, expected),
+                None => assert!(N < expected.len()),
+            },
+            Self::StackFromUtf8(bytes) => {
+                match (
+                    StackString::<N>::from_utf8(bytes),
+                    std::str::from_utf8(bytes),
+                ) {
+                    (Ok(s), Ok(expected)) => check_str(&s, expected),
+                    // Capacity is checked before UTF-8
+                    (Err(kstring::FromUtf8Error::Capacity(err)), _) => {
+                        assert_eq!((err.requested(), err.capacity()), (bytes.len(), N));
+                        assert!(N < bytes.len());
+                    }
+                    (Err(kstring::FromUtf8Error::Utf8(actual)), Err(expected)) => {
+                        assert_eq!(actual, expected);
+                    }
+                    (actual, expected) => panic!("{actual:?} != {expected:?}"),
+                }
+            }
+            Self::StackFromUtf8Lossy(bytes) => {
+                let expected = String::from_utf8_lossy(bytes);
+                match StackString::<N>::from_utf8_lossy(bytes) {
+                    Ok(s) => check_str(&s, &expected),
+                    Err(err) => {
+                        assert_eq!((err.requested(), err.capacity()), (expected.len(), N));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fuzz_target!(|input: Input<'_>| {
+    for constructor in input.constructors {
+        input.repr.run(constructor);
+    }
+});
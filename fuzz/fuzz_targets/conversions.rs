@@ -0,0 +1,269 @@
+#![no_main]
+
+use kstring::backend::HeapStr;
+use kstring::KStringBase;
+use kstring::KStringCowBase;
+use kstring::KStringRef;
+use kstring::StackString;
+use kstring::StorageKind;
+use kstring_fuzz::check;
+use kstring_fuzz::check_str;
+use kstring_fuzz::pick_static;
+use kstring_fuzz::Repr;
+use kstring_fuzz::Target;
+use libfuzzer_sys::fuzz_target;
+
+/// Where the conversions start from
+#[derive(Debug, arbitrary::Arbitrary)]
+enum Start<'a> {
+    Static(u8),
+    KString(&'a str),
+    Heap(&'a str),
+    CowBorrowed(&'a str),
+    CowOwned(&'a str),
+    RefStatic(u8),
+    RefBorrowed(&'a str),
+    Stack(&'a str),
+    String(&'a str),
+    Boxed(&'a str),
+}
+
+/// What to convert the current value into
+#[derive(Copy, Clone, Debug, arbitrary::Arbitrary)]
+enum Conversion {
+    /// Move into a `KStringBase`
+    KString,
+    /// Create a `KStringBase` from a reference to the current value
+    KStringFromRef,
+    /// Move into a `KStringCowBase`
+    Cow,
+    /// Borrow as a `KStringCowBase`, then take ownership of it
+    CowFromRef,
+    /// Borrow as a `KStringRef`, then take ownership of it
+    Ref,
+    Stack,
+    String,
+    Boxed,
+    CowStr,
+    /// Clone, dropping either the original or the clone
+    Clone(bool),
+}
+
+enum Value<'a, B, const N: usize> {
+    KString(KStringBase<B, N>),
+    Cow(KStringCowBase<'a, B, N>),
+    Ref(KStringRef<'a>),
+    Stack(StackString<N>),
+    String(String),
+    Boxed(Box<str>),
+}
+
+impl<'a, B: HeapStr, const N: usize> Value<'a, B, N> {
+    fn new(start: Start<'a>) -> (Self, String) {
+        match start {
+            Start::Static(i) => (
+                Self::KString(KStringBase::from_static(pick_static(i))),
+                pick_static(i).to_owned(),
+            ),
+            Start::KString(s) => (Self::KString(KStringBase::from_ref(s)), s.to_owned()),
+            Start::Heap(s) => (
+                Self::KString(KStringBase::from_heap(B::from_str(s))),
+                s.to_owned(),
+            ),
+            Start::CowBorrowed(s) => (Self::Cow(KStringCowBase::from_ref(s)), s.to_owned()),
+            Start::CowOwned(s) => (
+                Self::Cow(KStringCowBase::from_string(s.to_owned())),
+                s.to_owned(),
+            ),
+            Start::RefStatic(i) => (
+                Self::Ref(KStringRef::from_static(pick_static(i))),
+                pick_static(i).to_owned(),
+            ),
+            Start::RefBorrowed(s) => (Self::Ref(KStringRef::from_ref(s)), s.to_owned()),
+            Start::Stack(s) => match StackString::try_new(s) {
+                Some(stack) => (Self::Stack(stack), s.to_owned()),
+                None => (Self::String(s.to_owned()), s.to_owned()),
+            },
+            Start::String(s) => (Self::String(s.to_owned()), s.to_owned()),
+            Start::Boxed(s) => (Self::Boxed(s.into()), s.to_owned()),
+        }
+    }
+
+    fn check(&self, expected: &str) {
+        match self {
+            Self::KString(s) => check(s, expected),
+            Self::Cow(s) => {
+                check_str(s, expected);
+                check_str(&s.as_ref(), expected);
+                if s.storage() == StorageKind::Inline {
+                    assert!(expected.len() <= N);
+                }
+            }
+            Self::Ref(s) => {
+                check_str(s, expected);
+                assert_ne!(s.storage(), StorageKind::Inline);
+                assert_ne!(s.storage(), StorageKind::Heap);
+            }
+            Self::Stack(s) => check_str(s, expected),
+            Self::String(s) => assert_eq!(s, expected),
+            Self::Boxed(s) => assert_eq!(&**s, expected),
+        }
+    }
+
+    fn convert(self, conversion: Conversion, expected: &str) -> Self {
+        match conversion {
+            Conversion::KString => Self::KString(match self {
+                Self::KString(s) => s,
+                Self::Cow(s) => s.into(),
+                Self::Ref(s) => s.into(),
+                Self::Stack(s) => {
+                    KStringBase::try_inline(&s).expect("`StackString<N>` fits inline")
+                }
+                Self::String(s) => s.into(),
+                Self::Boxed(s) => s.into(),
+            }),
+            Conversion::KStringFromRef => Self::KString(match &self {
+                Self::KString(s) => KStringBase::from_ref(s),
+                Self::Cow(s) => s.into(),
+                Self::Ref(s) => s.into(),
+                Self::Stack(s) => KStringBase::from_ref(s),
+                Self::String(s) => s.into(),
+                Self::Boxed(s) => s.into(),
+            }),
+            Conversion::Cow => Self::Cow(match self {
+                Self::KString(s) => s.into(),
+                Self::Cow(s) => s,
+                Self::Ref(s) => s.into(),
+                Self::Stack(s) => KStringBase::try_inline(&s)
+                    .expect("`StackString<N>` fits inline")
+                    .into(),
+                Self::String(s) => s.into(),
+                Self::Boxed(s) => s.into(),
+            }),
+            Conversion::CowFromRef => {
+                let cow: KStringCowBase<'_, B, N> = match &self {
+                    Self::KString(s) => s.into(),
+                    Self::Cow(s) => s.as_ref().into(),
+                    Self::Ref(s) => s.into(),
+                    Self::Stack(s) => s.as_str().into(),
+                    Self::String(s) => s.into(),
+                    Self::Boxed(s) => s.into(),
+                };
+                check_str(&cow, expected);
+                Self::KString(cow.into_owned())
+            }
+            Conversion::Ref => {
+                let r: KStringRef<'_> = match &self {
+                    Self::KString(s) => s.into(),
+                    Self::Cow(s) => s.into(),
+                    Self::Ref(s) => *s,
+                    Self::Stack(s) => s.as_str().into(),
+                    Self::String(s) => s.into(),
+                    Self::Boxed(s) => s.into(),
+                };
+                check_str(&r, expected);
+                check_str(&r.to_owned::<B>(), expected);
+                let owned = Self::KString(r.into());
+                assert_eq!(r.into_mut(), expected);
+                owned
+            }
+            Conversion::Stack => match StackString::try_new(self.as_str()) {
+                Some(s) => Self::Stack(s),
+                None => {
+                    assert!(N < expected.len());
+                    self
+                }
+            },
+            Conversion::String => Self::String(match self {
+                Self::KString(s) => s.into_string(),
+                Self::Cow(s) => s.into_string(),
+                Self::Ref(s) => s.into_mut(),
+                Self::Stack(s) => s.as_str().to_owned(),
+                Self::String(s) => s,
+                Self::Boxed(s) => s.into_string(),
+            }),
+            Conversion::Boxed => Self::Boxed(match self {
+                Self::KString(s) => s.into_boxed_str(),
+                Self::Cow(s) => s.into_boxed_str(),
+                Self::Ref(s) => s.into_mut().into_boxed_str(),
+                Self::Stack(s) => s.as_str().into(),
+                Self::String(s) => s.into_boxed_str(),
+                Self::Boxed(s) => s,
+            }),
+            Conversion::CowStr => match self {
+                Self::KString(s) => {
+                    let is_static = s.storage() == StorageKind::Static;
+                    let cow = s.into_cow_str();
+                    assert_eq!(matches!(cow, std::borrow::Cow::Borrowed(_)), is_static);
+                    Self::String(cow.into_owned())
+                }
+                Self::Cow(s) => {
+                    let cow = s.into_cow_str();
+                    assert_eq!(cow, expected);
+                    match cow {
+                        std::borrow::Cow::Borrowed(s) => Self::Ref(KStringRef::from_ref(s)),
+                        std::borrow::Cow::Owned(s) => Self::String(s),
+                    }
+                }
+                other => other,
+            },
+            Conversion::Clone(keep_original) => {
+                let clone = self.clone();
+                clone.check(expected);
+                if keep_original {
+                    drop(clone);
+                    self
+                } else {
+                    drop(self);
+                    clone
+                }
+            }
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        match self {
+            Self::KString(s) => s,
+            Self::Cow(s) => s,
+            Self::Ref(s) => s,
+            Self::Stack(s) => s,
+            Self::String(s) => s,
+            Self::Boxed(s) => s,
+        }
+    }
+}
+
+impl<B: HeapStr, const N: usize> Clone for Value<'_, B, N> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::KString(s) => Self::KString(s.clone()),
+            Self::Cow(s) => Self::Cow(s.clone()),
+            Self::Ref(s) => Self::Ref(*s),
+            Self::Stack(s) => Self::Stack(*s),
+            Self::String(s) => Self::String(s.clone()),
+            Self::Boxed(s) => Self::Boxed(s.clone()),
+        }
+    }
+}
+
+#[derive(Debug, arbitrary::Arbitrary)]
+struct Input<'a> {
+    repr: Repr,
+    start: Start<'a>,
+    conversions: Vec<Conversion>,
+}
+
+impl Target for Input<'_> {
+    fn run<B: HeapStr, const N: usize>(self) {
+        let (mut value, expected) = Value::<B, N>::new(self.start);
+        value.check(&expected);
+        for conversion in self.conversions {
+            value = value.convert(conversion, &expected);
+            value.check(&expected);
+        }
+    }
+}
+
+fuzz_target!(|input: Input<'_>| {
+    input.repr.run(input);
+});
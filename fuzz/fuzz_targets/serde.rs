@@ -0,0 +1,109 @@
+#![no_main]
+
+use kstring::backend::HeapStr;
+use kstring::KStringBase;
+use kstring::KStringCowBase;
+use kstring::KStringRef;
+use kstring::StackString;
+use kstring::StorageKind;
+use kstring_fuzz::check_inlined;
+use kstring_fuzz::check_str;
+use kstring_fuzz::Repr;
+use kstring_fuzz::Target;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, arbitrary::Arbitrary)]
+enum Input<'a> {
+    /// Serialize then deserialize a string
+    Roundtrip(Repr, &'a str, &'a str),
+    /// Deserialize arbitrary JSON
+    Json(Repr, &'a [u8]),
+}
+
+impl Target for Input<'_> {
+    fn run<B: HeapStr, const N: usize>(self) {
+        match self {
+            Self::Roundtrip(_, s, previous) => roundtrip::<B, N>(s, previous),
+            Self::Json(_, json) => deserialize::<B, N>(json),
+        }
+    }
+}
+
+fn roundtrip<B: HeapStr, const N: usize>(expected: &str, previous: &str) {
+    let json = serde_json::to_string(expected).unwrap();
+
+    let s = KStringBase::<B, N>::from_ref(expected);
+    assert_eq!(serde_json::to_string(&s).unwrap(), json);
+    let cow = KStringCowBase::<B, N>::from_ref(expected);
+    assert_eq!(serde_json::to_string(&cow).unwrap(), json);
+    let r = KStringRef::from_ref(expected);
+    assert_eq!(serde_json::to_string(&r).unwrap(), json);
+    if let Some(stack) = StackString::<N>::try_new(expected) {
+        assert_eq!(serde_json::to_string(&stack).unwrap(), json);
+    }
+
+    let s: KStringBase<B, N> = serde_json::from_str(&json).unwrap();
+    check_inlined(&s, expected);
+    let cow: KStringCowBase<'_, B, N> = serde_json::from_str(&json).unwrap();
+    check_str(&cow, expected);
+
+    let mut place = KStringBase::<B, N>::from_ref(previous);
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    serde::Deserialize::deserialize_in_place(&mut deserializer, &mut place).unwrap();
+    kstring_fuzz::check(&place, expected);
+}
+
+fn deserialize<B: HeapStr, const N: usize>(json: &[u8]) {
+    let expected = serde_json::from_slice::<String>(json);
+
+    let actual = serde_json::from_slice::<KStringBase<B, N>>(json);
+    match (&actual, &expected) {
+        (Ok(actual), Ok(expected)) => check_inlined(actual, expected),
+        (Err(_), Err(_)) => {}
+        (actual, expected) => panic!("{actual:?} != {expected:?}"),
+    }
+
+    let actual = serde_json::from_slice::<KStringCowBase<'_, B, N>>(json);
+    match (&actual, &expected) {
+        (Ok(actual), Ok(expected)) => check_str(actual, expected),
+        (Err(_), Err(_)) => {}
+        (actual, expected) => panic!("{actual:?} != {expected:?}"),
+    }
+
+    // Only strings without escapes can be borrowed
+    let actual = serde_json::from_slice::<KStringRef<'_>>(json);
+    match (&actual, serde_json::from_slice::<&str>(json)) {
+        (Ok(actual), Ok(expected)) => {
+            check_str(actual, expected);
+            assert_eq!(actual.storage(), StorageKind::Borrowed);
+        }
+        (Err(_), Err(_)) => {}
+        (actual, expected) => panic!("{actual:?} != {expected:?}"),
+    }
+
+    let mut deserializer = serde_json::Deserializer::from_slice(json);
+    let actual = kstring::serde::borrow::deserialize::<_, B, N>(&mut deserializer)
+        .and_then(|s| deserializer.end().map(|()| s));
+    match (&actual, &expected) {
+        (Ok(actual), Ok(expected)) => {
+            check_str(actual, expected);
+            let borrowed = serde_json::from_slice::<&str>(json).is_ok();
+            assert_eq!(actual.storage() == StorageKind::Borrowed, borrowed);
+        }
+        (Err(_), Err(_)) => {}
+        (actual, expected) => panic!("{actual:?} != {expected:?}"),
+    }
+
+    let actual = serde_json::from_slice::<StackString<N>>(json);
+    match (&actual, &expected) {
+        (Ok(actual), Ok(expected)) => check_str(actual, expected),
+        (Err(_), Ok(expected)) => assert!(N < expected.len()),
+        (Err(_), Err(_)) => {}
+        (actual, expected) => panic!("{actual:?} != {expected:?}"),
+    }
+}
+
+fuzz_target!(|input: Input<'_>| {
+    let (Input::Roundtrip(repr, ..) | Input::Json(repr, _)) = input;
+    repr.run(input);
+});
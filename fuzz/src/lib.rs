@@ -0,0 +1,105 @@
+//! Shared setup for the fuzz targets
+//!
+//! Each target checks `kstring`'s types against [`String`] as the oracle, across every
+//! [`HeapStr`] backend and a range of inline capacities.
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::hash::BuildHasher as _;
+use std::hash::Hash;
+use std::ops::Deref;
+
+use kstring::backend::HeapStr;
+use kstring::KStringBase;
+use kstring::StorageKind;
+
+/// `'static` strings for the targets to pick from, on either side of the inline capacities
+pub const STATICS: &[&str] = &[
+    "",
+    "key",
+    "fourteen chars",
+    "fifteen chars!!",
+    "sixteen chars!!!",
+    "a static string that is too long to fit inline",
+];
+
+/// Pick one of [`STATICS`]
+pub fn pick_static(index: u8) -> &'static str {
+    STATICS[usize::from(index) % STATICS.len()]
+}
+
+/// A fuzz target, generic over the `KStringBase` it exercises
+pub trait Target {
+    fn run<B: HeapStr, const N: usize>(self);
+}
+
+/// The [`HeapStr`] backend and inline capacity to fuzz
+#[derive(Copy, Clone, Debug, arbitrary::Arbitrary)]
+pub enum Repr {
+    Boxed0,
+    Boxed15,
+    Boxed22,
+    Boxed255,
+    Arc15,
+    Rc22,
+    ThinBoxed14,
+    ThinArc14,
+}
+
+impl Repr {
+    pub fn run(self, target: impl Target) {
+        use kstring::backend::{ArcStr, BoxedStr, RcStr, ThinArcStr, ThinBoxedStr};
+
+        match self {
+            Self::Boxed0 => target.run::<BoxedStr, 0>(),
+            Self::Boxed15 => target.run::<BoxedStr, 15>(),
+            Self::Boxed22 => target.run::<BoxedStr, 22>(),
+            Self::Boxed255 => target.run::<BoxedStr, 255>(),
+            Self::Arc15 => target.run::<ArcStr, 15>(),
+            Self::Rc22 => target.run::<RcStr, 22>(),
+            Self::ThinBoxed14 => target.run::<ThinBoxedStr, 14>(),
+            Self::ThinArc14 => target.run::<ThinArcStr, 14>(),
+        }
+    }
+}
+
+/// Check a string type against `expected`, through every way of reading it
+pub fn check_str<T>(actual: &T, expected: &str)
+where
+    T: Deref<Target = str> + Hash + Debug + Display + ?Sized,
+{
+    assert_eq!(&**actual, expected);
+    assert_eq!(actual.len(), expected.len());
+    assert_eq!(actual.is_empty(), expected.is_empty());
+    assert_eq!(actual.to_string(), expected);
+    let hasher = std::hash::RandomState::new();
+    assert_eq!(hasher.hash_one(actual), hasher.hash_one(expected));
+}
+
+/// Check a `KStringBase` against `expected`, including where it is stored
+pub fn check<B: HeapStr, const N: usize>(actual: &KStringBase<B, N>, expected: &str) {
+    check_str(actual, expected);
+    assert_eq!(actual, expected);
+    assert_eq!(actual.as_ref().as_str(), expected);
+    match actual.storage() {
+        StorageKind::Inline => assert!(expected.len() <= N, "{actual:?} is too long to inline"),
+        StorageKind::Static | StorageKind::Heap => {}
+        kind => panic!("{actual:?} is owned but stored as {kind:?}"),
+    }
+    let heap_bytes = match actual.storage() {
+        StorageKind::Heap => expected.len(),
+        _ => 0,
+    };
+    assert_eq!(actual.heap_bytes(), heap_bytes);
+}
+
+/// Check that a `KStringBase` was stored inline whenever it fits
+pub fn check_inlined<B: HeapStr, const N: usize>(actual: &KStringBase<B, N>, expected: &str) {
+    check(actual, expected);
+    let storage = if expected.len() <= N {
+        StorageKind::Inline
+    } else {
+        StorageKind::Heap
+    };
+    assert_eq!(actual.storage(), storage, "{actual:?}");
+}
@@ -3,29 +3,97 @@ use alloc::boxed::Box;
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
 
-#[cfg(feature = "arc")]
+#[cfg(all(feature = "thin", feature = "arc"))]
+pub(crate) type DefaultStr = ThinArcStr;
+#[cfg(all(feature = "thin", not(feature = "arc")))]
+pub(crate) type DefaultStr = ThinBoxedStr;
+#[cfg(all(not(feature = "thin"), feature = "arc"))]
 pub(crate) type DefaultStr = ArcStr;
-#[cfg(not(feature = "arc"))]
+#[cfg(all(not(feature = "thin"), not(feature = "arc")))]
 pub(crate) type DefaultStr = BoxedStr;
 
 /// Fast allocations, O(n) clones
 pub type BoxedStr = Box<str>;
-static_assertions::assert_eq_size!(DefaultStr, BoxedStr);
 
 /// Cross-thread, O(1) clones
 pub type ArcStr = alloc::sync::Arc<str>;
-static_assertions::assert_eq_size!(DefaultStr, ArcStr);
 
 /// O(1) clones
 pub type RcStr = alloc::rc::Rc<str>;
-static_assertions::assert_eq_size!(DefaultStr, RcStr);
+
+#[cfg(feature = "unsafe")]
+pub use thin::ThinArcStr;
+#[cfg(feature = "unsafe")]
+pub use thin::ThinBoxedStr;
 
 /// Abstract over different type of heap-allocated strings
-pub trait HeapStr: core::fmt::Debug + Clone + private::Sealed {
+///
+/// Besides [`BoxedStr`], [`ArcStr`], and [`RcStr`], this can be implemented for your own
+/// refcounted, arena-backed, or otherwise specialized strings to use with
+/// [`KStringBase`][crate::KStringBase].
+///
+/// # Implementing
+///
+/// `KStringBase` relies on the following being upheld:
+///
+/// - [`HeapStr::as_str`] must return the same string for the life of the value and for every
+///   value produced by [`Clone::clone`], otherwise `Eq`, `Ord`, and `Hash` will be inconsistent
+/// - `Self` is stored alongside the inline and `&'static str` variants, so its size bounds the
+///   size of `KStringBase<Self>`.  Pointer-sized backends, like `ThinBoxedStr`, allow the most
+///   compact layouts, though only with the `thin` feature, which packs a `&'static str` next to
+///   the tag; otherwise a `&'static str` takes two words.
+///
+/// # Examples
+///
+/// ```rust
+/// #[derive(Clone, Debug)]
+/// struct SharedStr(std::rc::Rc<str>);
+///
+/// impl kstring::backend::HeapStr for SharedStr {
+///     fn from_str(other: &str) -> Self {
+///         Self(other.into())
+///     }
+///     fn from_string(other: String) -> Self {
+///         Self(other.into())
+///     }
+///     fn from_boxed_str(other: kstring::backend::BoxedStr) -> Self {
+///         Self(other.into())
+///     }
+///     fn as_str(&self) -> &str {
+///         &self.0
+///     }
+/// }
+///
+/// let s = kstring::KStringBase::<SharedStr>::from_ref("a string too long to be inlined");
+/// assert_eq!(s, "a string too long to be inlined");
+/// ```
+pub trait HeapStr: core::fmt::Debug + Clone {
+    /// Copy `other` into a new heap allocation
     fn from_str(other: &str) -> Self;
+    /// Take ownership of `other`, reusing its allocation if possible
     fn from_string(other: String) -> Self;
+    /// Take ownership of `other`, reusing its allocation if possible
     fn from_boxed_str(other: BoxedStr) -> Self;
+    /// Extracts a string slice containing the entire string
     fn as_str(&self) -> &str;
+
+    /// Bytes of heap memory attributed to this handle, for memory accounting
+    ///
+    /// This defaults to the length of the string.  Backends should include any headers stored
+    /// in the allocation and, when clones share the allocation, divide it between them so totals
+    /// aren't double counted.
+    #[inline]
+    fn heap_size(&self) -> usize {
+        self.as_str().len()
+    }
+}
+
+/// Size of a reference-counted allocation of `value`, split between `count` handles
+fn shared_size(value: core::alloc::Layout, count: usize) -> usize {
+    let (layout, _) = core::alloc::Layout::new::<[usize; 2]>()
+        .extend(value)
+        .expect("string length overflows `isize`");
+    layout.pad_to_align().size() / count
 }
 
 impl HeapStr for BoxedStr {
@@ -58,7 +126,7 @@ impl HeapStr for ArcStr {
 
     #[inline]
     fn from_string(other: String) -> Self {
-        other.into_boxed_str().into()
+        other.into()
     }
 
     #[inline]
@@ -70,6 +138,14 @@ impl HeapStr for ArcStr {
     fn as_str(&self) -> &str {
         self
     }
+
+    #[inline]
+    fn heap_size(&self) -> usize {
+        shared_size(
+            core::alloc::Layout::for_value::<str>(self),
+            Self::strong_count(self),
+        )
+    }
 }
 
 impl HeapStr for RcStr {
@@ -80,7 +156,7 @@ impl HeapStr for RcStr {
 
     #[inline]
     fn from_string(other: String) -> Self {
-        other.into_boxed_str().into()
+        other.into()
     }
 
     #[inline]
@@ -92,11 +168,283 @@ impl HeapStr for RcStr {
     fn as_str(&self) -> &str {
         self
     }
+
+    #[inline]
+    fn heap_size(&self) -> usize {
+        shared_size(
+            core::alloc::Layout::for_value::<str>(self),
+            Self::strong_count(self),
+        )
+    }
 }
 
-pub(crate) mod private {
-    pub trait Sealed {}
-    impl Sealed for super::BoxedStr {}
-    impl Sealed for super::ArcStr {}
-    impl Sealed for super::RcStr {}
+#[cfg(feature = "unsafe")]
+mod thin {
+    use core::alloc::Layout;
+    use core::ptr::NonNull;
+    use core::sync::atomic::AtomicUsize;
+    use core::sync::atomic::Ordering;
+
+    use super::BoxedStr;
+    use super::HeapStr;
+    #[cfg(not(feature = "std"))]
+    use alloc::string::String;
+
+    /// Fast allocations, O(n) clones through a pointer-sized handle
+    ///
+    /// The length is stored in the allocation, ahead of the string, rather than in the handle as
+    /// with [`BoxedStr`].  Enable the `thin` feature to make this the backend for
+    /// [`KString`][crate::KString], shrinking it to two words.
+    pub struct ThinBoxedStr(NonNull<BoxedHeader>);
+
+    #[repr(C)]
+    struct BoxedHeader {
+        len: usize,
+    }
+
+    /// Cross-thread, O(1) clones through a pointer-sized handle
+    ///
+    /// The reference count and length are stored in the allocation, ahead of the string, rather
+    /// than in the handle as with [`ArcStr`][super::ArcStr].  Enable the `thin` and `arc`
+    /// features to make this the backend for [`KString`][crate::KString], shrinking it to two
+    /// words.
+    pub struct ThinArcStr(NonNull<ArcHeader>);
+
+    #[repr(C)]
+    struct ArcHeader {
+        count: AtomicUsize,
+        len: usize,
+    }
+
+    // Like `Arc`, guard against the count wrapping around to zero
+    const MAX_COUNT: usize = isize::MAX as usize;
+
+    static_assertions::assert_eq_size!(ThinBoxedStr, usize);
+    static_assertions::assert_eq_size!(Option<ThinBoxedStr>, usize);
+    static_assertions::assert_eq_size!(ThinArcStr, usize);
+    static_assertions::assert_eq_size!(Option<ThinArcStr>, usize);
+
+    /// Layout of a `H` followed by `len` bytes, and the offset of those bytes
+    fn layout<H>(len: usize) -> (Layout, usize) {
+        let (layout, offset) = Layout::new::<H>()
+            .extend(Layout::array::<u8>(len).expect("string length overflows `isize`"))
+            .expect("string length overflows `isize`");
+        (layout.pad_to_align(), offset)
+    }
+
+    /// Allocate `header` followed by a copy of `other`
+    fn allocate<H>(header: H, other: &str) -> NonNull<H> {
+        let (layout, offset) = layout::<H>(other.len());
+        unsafe {
+            // SAFETY: `layout` always includes the header, so is never zero-sized
+            let ptr = alloc::alloc::alloc(layout);
+            let Some(ptr) = NonNull::new(ptr) else {
+                alloc::alloc::handle_alloc_error(layout)
+            };
+            // SAFETY: `layout` is sized and aligned for the header followed by `other`
+            let header_ptr = ptr.cast::<H>();
+            header_ptr.as_ptr().write(header);
+            ptr.as_ptr()
+                .add(offset)
+                .copy_from_nonoverlapping(other.as_ptr(), other.len());
+            header_ptr
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must come from [`allocate`] with a string of `len` bytes and not yet be freed
+    unsafe fn as_str<'s, H>(ptr: NonNull<H>, len: usize) -> &'s str {
+        let (_, offset) = layout::<H>(len);
+        unsafe {
+            // SAFETY: `len` bytes of UTF-8 were copied after the header by `allocate` and are
+            // never mutated
+            let data = ptr.as_ptr().cast::<u8>().add(offset);
+            let bytes = core::slice::from_raw_parts(data, len);
+            core::str::from_utf8_unchecked(bytes)
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `ptr` must come from [`allocate`] with a string of `len` bytes and not be used afterwards
+    unsafe fn deallocate<H>(ptr: NonNull<H>, len: usize) {
+        let (layout, _) = layout::<H>(len);
+        unsafe {
+            // SAFETY: `layout` matches the allocation, per the caller
+            alloc::alloc::dealloc(ptr.as_ptr().cast::<u8>(), layout);
+        }
+    }
+
+    impl ThinBoxedStr {
+        fn len(&self) -> usize {
+            unsafe {
+                // SAFETY: The header is initialized on allocation and lives as long as the handle
+                self.0.as_ref().len
+            }
+        }
+    }
+
+    impl HeapStr for ThinBoxedStr {
+        #[inline]
+        fn from_str(other: &str) -> Self {
+            Self(allocate(BoxedHeader { len: other.len() }, other))
+        }
+
+        #[inline]
+        fn from_string(other: String) -> Self {
+            Self::from_str(other.as_str())
+        }
+
+        #[inline]
+        fn from_boxed_str(other: BoxedStr) -> Self {
+            Self::from_str(&other)
+        }
+
+        #[inline]
+        fn as_str(&self) -> &str {
+            unsafe {
+                // SAFETY: `self.0` is live for as long as `self`
+                as_str(self.0, self.len())
+            }
+        }
+
+        #[inline]
+        fn heap_size(&self) -> usize {
+            let (layout, _) = layout::<BoxedHeader>(self.len());
+            layout.size()
+        }
+    }
+
+    #[cfg(feature = "deepsize")]
+    impl deepsize::DeepSizeOf for ThinBoxedStr {
+        #[inline]
+        fn deep_size_of_children(&self, _context: &mut deepsize::Context) -> usize {
+            self.heap_size()
+        }
+    }
+
+    impl Clone for ThinBoxedStr {
+        #[inline]
+        fn clone(&self) -> Self {
+            Self::from_str(self.as_str())
+        }
+    }
+
+    impl Drop for ThinBoxedStr {
+        fn drop(&mut self) {
+            unsafe {
+                // SAFETY: We are the only handle to this allocation
+                deallocate(self.0, self.len());
+            }
+        }
+    }
+
+    impl core::fmt::Debug for ThinBoxedStr {
+        #[inline]
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            core::fmt::Debug::fmt(self.as_str(), f)
+        }
+    }
+
+    // SAFETY: The allocation is uniquely owned and immutable, as with `Box<str>`
+    unsafe impl Send for ThinBoxedStr {}
+    // SAFETY: The allocation is uniquely owned and immutable, as with `Box<str>`
+    unsafe impl Sync for ThinBoxedStr {}
+
+    impl ThinArcStr {
+        fn header(&self) -> &ArcHeader {
+            unsafe {
+                // SAFETY: The header is initialized on allocation and lives as long as any handle
+                self.0.as_ref()
+            }
+        }
+    }
+
+    impl HeapStr for ThinArcStr {
+        #[inline]
+        fn from_str(other: &str) -> Self {
+            let header = ArcHeader {
+                count: AtomicUsize::new(1),
+                len: other.len(),
+            };
+            Self(allocate(header, other))
+        }
+
+        #[inline]
+        fn from_string(other: String) -> Self {
+            Self::from_str(other.as_str())
+        }
+
+        #[inline]
+        fn from_boxed_str(other: BoxedStr) -> Self {
+            Self::from_str(&other)
+        }
+
+        #[inline]
+        fn as_str(&self) -> &str {
+            unsafe {
+                // SAFETY: `self.0` is live for as long as any handle
+                as_str(self.0, self.header().len)
+            }
+        }
+
+        #[inline]
+        fn heap_size(&self) -> usize {
+            let header = self.header();
+            let (layout, _) = layout::<ArcHeader>(header.len);
+            layout.size() / header.count.load(Ordering::Relaxed)
+        }
+    }
+
+    /// Splits the allocation between handles, like [`HeapStr::heap_size`], as only `deepsize`'s
+    /// own `Arc` and `Rc` can be tracked through its `Context`
+    #[cfg(feature = "deepsize")]
+    impl deepsize::DeepSizeOf for ThinArcStr {
+        #[inline]
+        fn deep_size_of_children(&self, _context: &mut deepsize::Context) -> usize {
+            self.heap_size()
+        }
+    }
+
+    impl Clone for ThinArcStr {
+        #[inline]
+        fn clone(&self) -> Self {
+            // Relaxed is enough as we already hold a reference, see `Arc::clone`
+            let previous = self.header().count.fetch_add(1, Ordering::Relaxed);
+            assert!(
+                previous <= MAX_COUNT,
+                "`ThinArcStr` reference count overflow"
+            );
+            Self(self.0)
+        }
+    }
+
+    impl Drop for ThinArcStr {
+        fn drop(&mut self) {
+            let header = self.header();
+            if header.count.fetch_sub(1, Ordering::Release) != 1 {
+                return;
+            }
+            // Synchronize with the other handles' releases before freeing, see `Arc::drop`
+            core::sync::atomic::fence(Ordering::Acquire);
+            let len = header.len;
+            unsafe {
+                // SAFETY: This was the last handle
+                deallocate(self.0, len);
+            }
+        }
+    }
+
+    impl core::fmt::Debug for ThinArcStr {
+        #[inline]
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            core::fmt::Debug::fmt(self.as_str(), f)
+        }
+    }
+
+    // SAFETY: The string is immutable and the count is atomic, as with `Arc<str>`
+    unsafe impl Send for ThinArcStr {}
+    // SAFETY: The string is immutable and the count is atomic, as with `Arc<str>`
+    unsafe impl Sync for ThinArcStr {}
 }
@@ -0,0 +1,210 @@
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::fmt;
+
+use crate::stack::StackString;
+use crate::KStringBase;
+
+/// Incrementally build a [`KStringBase`], staying inline until the string outgrows it
+///
+/// Text is written into a [`StackString`] and only moved to the heap once it no longer fits, so
+/// short results never allocate.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Write as _;
+///
+/// let mut builder = kstring::KStringBuilder::new();
+/// builder.push_str("item");
+/// write!(builder, "-{}", 42).unwrap();
+/// builder.extend(['-', 'a']);
+/// let key: kstring::KString = builder.finish();
+/// assert_eq!(key, "item-42-a");
+/// ```
+pub struct KStringBuilder<
+    B = crate::backend::DefaultStr,
+    const N: usize = { crate::string::CAPACITY },
+> {
+    inner: KStringBuilderInner<N>,
+    marker: core::marker::PhantomData<B>,
+}
+
+#[derive(Clone)]
+enum KStringBuilderInner<const N: usize> {
+    Inline(StackString<N>),
+    Heap(String),
+}
+
+impl<B, const N: usize> KStringBuilder<B, N> {
+    /// Create an empty `KStringBuilder`.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            inner: KStringBuilderInner::Inline(StackString::EMPTY),
+            marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Create an empty `KStringBuilder`, going straight to the heap if `capacity` won't fit
+    /// inline.
+    ///
+    /// Reserving exactly the final length lets [`finish`][Self::finish] hand the allocation to a
+    /// [`BoxedStr`][crate::backend::BoxedStr] as-is.
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let inner = if capacity <= N {
+            KStringBuilderInner::Inline(StackString::EMPTY)
+        } else {
+            KStringBuilderInner::Heap(String::with_capacity(capacity))
+        };
+        Self {
+            inner,
+            marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Appends the given [`char`] to the end of this `KStringBuilder`.
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Appends a given string slice onto the end of this `KStringBuilder`.
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        match &mut self.inner {
+            KStringBuilderInner::Inline(inline) => {
+                if inline.try_push_str(s).is_err() {
+                    // Grow like `String` would from a full inline buffer, so later pushes don't
+                    // reallocate right away
+                    let capacity = (inline.len() + s.len()).max(2 * N);
+                    let mut heap = String::with_capacity(capacity);
+                    heap.push_str(inline.as_str());
+                    heap.push_str(s);
+                    self.inner = KStringBuilderInner::Heap(heap);
+                }
+            }
+            KStringBuilderInner::Heap(heap) => heap.push_str(s),
+        }
+    }
+
+    /// Extracts a string slice containing the entire `KStringBuilder`.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match &self.inner {
+            KStringBuilderInner::Inline(inline) => inline.as_str(),
+            KStringBuilderInner::Heap(heap) => heap.as_str(),
+        }
+    }
+
+    /// Returns the length of this `KStringBuilder`, in bytes.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.as_str().len()
+    }
+
+    /// Returns `true` if this `KStringBuilder` has a length of zero, and `false` otherwise.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the string has outgrown inline storage.
+    #[inline]
+    #[must_use]
+    pub fn is_heap(&self) -> bool {
+        matches!(self.inner, KStringBuilderInner::Heap(_))
+    }
+}
+
+impl<B: crate::backend::HeapStr, const N: usize> KStringBuilder<B, N> {
+    /// Convert into a `KStringBase`.
+    ///
+    /// Inline strings are moved as-is.  Heap strings are handed to [`HeapStr::from_string`], so
+    /// the buffer is reused whenever the backend can:
+    /// - [`BoxedStr`] takes the allocation as-is when the string exactly fills it, like after
+    ///   [`with_capacity`][Self::with_capacity] with the final length, and otherwise shrinks it
+    /// - Backends that keep a header in their allocation, like [`ArcStr`] and the thin backends,
+    ///   copy into it
+    ///
+    /// [`HeapStr::from_string`]: crate::backend::HeapStr::from_string
+    /// [`BoxedStr`]: crate::backend::BoxedStr
+    /// [`ArcStr`]: crate::backend::ArcStr
+    #[inline]
+    #[must_use]
+    pub fn finish(self) -> KStringBase<B, N> {
+        match self.inner {
+            KStringBuilderInner::Inline(inline) => KStringBase::from_inline(inline),
+            KStringBuilderInner::Heap(heap) => KStringBase::from_string(heap),
+        }
+    }
+}
+
+impl<B, const N: usize> Clone for KStringBuilder<B, N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            marker: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<B, const N: usize> Default for KStringBuilder<B, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B, const N: usize> fmt::Debug for KStringBuilder<B, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<B, const N: usize> fmt::Write for KStringBuilder<B, N> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.push(c);
+        Ok(())
+    }
+}
+
+impl<B, const N: usize> Extend<char> for KStringBuilder<B, N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        for c in iter {
+            self.push(c);
+        }
+    }
+}
+
+impl<'s, B, const N: usize> Extend<&'s str> for KStringBuilder<B, N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = &'s str>>(&mut self, iter: I) {
+        for s in iter {
+            self.push_str(s);
+        }
+    }
+}
+
+impl<B: crate::backend::HeapStr, const N: usize> From<KStringBuilder<B, N>> for KStringBase<B, N> {
+    #[inline]
+    fn from(other: KStringBuilder<B, N>) -> Self {
+        other.finish()
+    }
+}
@@ -0,0 +1,88 @@
+//! `PartialEq` and `PartialOrd` between the string types and other strings
+//!
+//! Every comparison is implemented in both directions and behaves like comparing the `str`s.
+
+use alloc::borrow::Cow;
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+use core::cmp::Ordering;
+
+use crate::backend::HeapStr;
+use crate::KStringBase;
+use crate::KStringCowBase;
+use crate::KStringRef;
+use crate::StackString;
+
+/// Compare strings, skipping the bytes when both point at the same data
+///
+/// This is common when comparing a `KStringCow` or `KStringRef` with the string it borrows from.
+#[inline]
+fn eq(lhs: &str, rhs: &str) -> bool {
+    core::ptr::eq(lhs, rhs) || lhs == rhs
+}
+
+macro_rules! impl_cmp {
+    ($([$($generics:tt)*] $lhs:ty, $rhs:ty;)*) => {$(
+        impl<$($generics)*> PartialEq<$rhs> for $lhs {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                eq(AsRef::<str>::as_ref(self), AsRef::<str>::as_ref(other))
+            }
+        }
+
+        impl<$($generics)*> PartialEq<$lhs> for $rhs {
+            #[inline]
+            fn eq(&self, other: &$lhs) -> bool {
+                eq(AsRef::<str>::as_ref(self), AsRef::<str>::as_ref(other))
+            }
+        }
+
+        impl<$($generics)*> PartialOrd<$rhs> for $lhs {
+            #[inline]
+            fn partial_cmp(&self, other: &$rhs) -> Option<Ordering> {
+                PartialOrd::partial_cmp(AsRef::<str>::as_ref(self), AsRef::<str>::as_ref(other))
+            }
+        }
+
+        impl<$($generics)*> PartialOrd<$lhs> for $rhs {
+            #[inline]
+            fn partial_cmp(&self, other: &$lhs) -> Option<Ordering> {
+                PartialOrd::partial_cmp(AsRef::<str>::as_ref(self), AsRef::<str>::as_ref(other))
+            }
+        }
+    )*};
+}
+
+impl_cmp! {
+    [B: HeapStr, const N: usize] KStringBase<B, N>, str;
+    ['a, B: HeapStr, const N: usize] KStringBase<B, N>, &'a str;
+    [B: HeapStr, const N: usize] KStringBase<B, N>, String;
+    [B: HeapStr, const N: usize] KStringBase<B, N>, Box<str>;
+    ['a, B: HeapStr, const N: usize] KStringBase<B, N>, Cow<'a, str>;
+    ['s, B: HeapStr, const N: usize] KStringBase<B, N>, KStringCowBase<'s, B, N>;
+    ['s, B: HeapStr, const N: usize] KStringBase<B, N>, KStringRef<'s>;
+    [B: HeapStr, const N: usize, const C: usize] KStringBase<B, N>, StackString<C>;
+
+    ['s, B: HeapStr, const N: usize] KStringCowBase<'s, B, N>, str;
+    ['s, 'a, B: HeapStr, const N: usize] KStringCowBase<'s, B, N>, &'a str;
+    ['s, B: HeapStr, const N: usize] KStringCowBase<'s, B, N>, String;
+    ['s, B: HeapStr, const N: usize] KStringCowBase<'s, B, N>, Box<str>;
+    ['s, 'a, B: HeapStr, const N: usize] KStringCowBase<'s, B, N>, Cow<'a, str>;
+    ['s, 'r, B: HeapStr, const N: usize] KStringCowBase<'s, B, N>, KStringRef<'r>;
+    ['s, B: HeapStr, const N: usize, const C: usize] KStringCowBase<'s, B, N>, StackString<C>;
+
+    ['s] KStringRef<'s>, str;
+    ['s, 'a] KStringRef<'s>, &'a str;
+    ['s] KStringRef<'s>, String;
+    ['s] KStringRef<'s>, Box<str>;
+    ['s, 'a] KStringRef<'s>, Cow<'a, str>;
+    ['s, const C: usize] KStringRef<'s>, StackString<C>;
+
+    [const C: usize] StackString<C>, str;
+    ['a, const C: usize] StackString<C>, &'a str;
+    [const C: usize] StackString<C>, String;
+    [const C: usize] StackString<C>, Box<str>;
+    ['a, const C: usize] StackString<C>, Cow<'a, str>;
+}
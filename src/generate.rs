@@ -0,0 +1,72 @@
+//! Shared logic for generating values that cover every storage variant
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use crate::backend::HeapStr;
+use crate::KStringBase;
+use crate::StackString;
+
+/// `'static` strings for [`Shape::Static`], covering empty, short and long
+pub(crate) const STATICS: &[&str] = &[
+    "",
+    "static",
+    "a static string that is too long to fit inline",
+];
+
+/// Which storage a generated string should land in
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Shape {
+    /// One of [`STATICS`]
+    Static,
+    /// Anything that fits inline
+    Inline,
+    /// Exactly fills the inline capacity
+    Full,
+    /// Too long to fit inline, at least one byte past the capacity
+    Heap,
+}
+
+impl Shape {
+    pub(crate) const ALL: [Self; 4] = [Self::Static, Self::Inline, Self::Full, Self::Heap];
+
+    /// Resize `s` to fit this shape for an inline capacity of `capacity`
+    ///
+    /// Strings are truncated on a `char` boundary and padded with ASCII.
+    pub(crate) fn resize(self, s: &str, capacity: usize) -> String {
+        let (min, max) = match self {
+            Self::Static | Self::Inline => (0, capacity),
+            Self::Full => (capacity, capacity),
+            Self::Heap => (capacity + 1, usize::MAX),
+        };
+        let mut s = String::from(&s[..s.floor_char_boundary(max)]);
+        while s.len() < min {
+            s.push('x');
+        }
+        s
+    }
+
+    /// Create a `KStringBase` with this shape, seeded by `s` and `index`
+    pub(crate) fn kstring<B: HeapStr, const N: usize>(
+        self,
+        s: &str,
+        index: usize,
+    ) -> KStringBase<B, N> {
+        match self {
+            Self::Static => KStringBase::from_static(STATICS[index % STATICS.len()]),
+            Self::Inline | Self::Full | Self::Heap => KStringBase::from_string(self.resize(s, N)),
+        }
+    }
+
+    /// Create a `StackString` with this shape, seeded by `s`
+    ///
+    /// As a `StackString` is always inline, [`Shape::Static`] and [`Shape::Heap`] fall back to
+    /// [`Shape::Inline`] and [`Shape::Full`].
+    pub(crate) fn stack_string<const CAPACITY: usize>(self, s: &str) -> StackString<CAPACITY> {
+        let shape = match self {
+            Self::Static | Self::Inline => Self::Inline,
+            Self::Full | Self::Heap => Self::Full,
+        };
+        StackString::new(&shape.resize(s, CAPACITY))
+    }
+}
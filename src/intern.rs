@@ -0,0 +1,249 @@
+//! Deduplicate repeated keys so they share one allocation
+//!
+//! Strings short enough to be inlined never touch the interner; they are already
+//! allocation-free.  Longer strings are looked up in a table and, on a hit, the existing
+//! allocation is shared.
+//!
+//! - [`Interner`] hands out [`ArcStr`]-backed strings and may be shared between threads
+//! - [`LocalInterner`] hands out [`RcStr`]-backed strings for single-threaded use, avoiding
+//!   atomic reference counting and locking
+//!
+//! # Examples
+//!
+//! ```rust
+//! let interner = kstring::intern::Interner::new();
+//! let first = interner.intern("tenant-0123456789abcdef-0123456789abcdef");
+//! let second = interner.intern("tenant-0123456789abcdef-0123456789abcdef");
+//! assert_eq!(first, second);
+//! assert_eq!(first.as_ptr(), second.as_ptr());
+//!
+//! drop(first);
+//! drop(second);
+//! assert_eq!(interner.purge(), 1);
+//! assert!(interner.is_empty());
+//! ```
+
+use core::cell::Cell;
+use core::cell::RefCell;
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::OnceLock;
+
+use crate::backend::ArcStr;
+use crate::backend::HeapStr as _;
+use crate::backend::RcStr;
+use crate::KStringBase;
+
+/// Thread-safe table of shared strings
+///
+/// Entries are kept alive by the interner until [`Interner::purge`] finds that no
+/// `KStringBase` refers to them anymore.
+///
+/// # Limitations
+///
+/// - Nothing is reclaimed on its own: dropping the last `KStringBase` for a string leaves its
+///   entry, and allocation, in the table until [`Interner::purge`] is called
+/// - Every lookup locks one `Mutex` around the whole table, so threads interning at the same time
+///   contend on it
+#[derive(Debug, Default)]
+pub struct Interner {
+    entries: Mutex<HashSet<ArcStr>>,
+}
+
+impl Interner {
+    /// Create an empty `Interner`.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The process-wide `Interner` used by [`KStringBase::intern`].
+    #[must_use]
+    pub fn global() -> &'static Self {
+        static GLOBAL: OnceLock<Interner> = OnceLock::new();
+        GLOBAL.get_or_init(Self::new)
+    }
+
+    /// Get a `KStringBase` for `other`, sharing storage with any previous call for the same
+    /// string.
+    #[must_use]
+    pub fn intern(&self, other: &str) -> KStringBase<ArcStr> {
+        if let Some(inline) = KStringBase::try_inline(other) {
+            return inline;
+        }
+
+        KStringBase::from_heap(self.share(other))
+    }
+
+    /// Look up, or insert, the shared allocation for `other`
+    pub(crate) fn share(&self, other: &str) -> ArcStr {
+        let mut entries = self.entries();
+        if let Some(existing) = entries.get(other) {
+            existing.clone()
+        } else {
+            let created = ArcStr::from_str(other);
+            entries.insert(created.clone());
+            created
+        }
+    }
+
+    /// Drop every entry no longer referenced outside of the `Interner`, returning how many
+    /// were removed.
+    pub fn purge(&self) -> usize {
+        let mut entries = self.entries();
+        let before = entries.len();
+        entries.retain(|s| 1 < ArcStr::strong_count(s));
+        before - entries.len()
+    }
+
+    /// Number of distinct strings currently held.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries().len()
+    }
+
+    /// Returns `true` if no strings are currently held.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries().is_empty()
+    }
+
+    fn entries(&self) -> MutexGuard<'_, HashSet<ArcStr>> {
+        // The table is never left in an inconsistent state, so a panic elsewhere doesn't
+        // invalidate it.
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Single-threaded table of shared strings
+///
+/// Entries are kept alive by the interner until [`LocalInterner::purge`] finds that no
+/// `KStringBase` refers to them anymore or the table is [cleared][LocalInterner::clear].
+///
+/// # Examples
+///
+/// ```rust
+/// let interner = kstring::intern::LocalInterner::new();
+/// for _ in 0..3 {
+///     let _ = interner.intern("a-document-field-name-too-long-to-inline");
+/// }
+/// let stats = interner.stats();
+/// assert_eq!(stats.misses, 1);
+/// assert_eq!(stats.hits, 2);
+///
+/// interner.clear();
+/// assert!(interner.is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct LocalInterner {
+    entries: RefCell<HashSet<RcStr>>,
+    stats: Cell<InternStats>,
+}
+
+impl LocalInterner {
+    /// Create an empty `LocalInterner`.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Access the current thread's `LocalInterner`, as used by [`KStringBase::intern_local`].
+    pub fn with_thread_local<R>(f: impl FnOnce(&Self) -> R) -> R {
+        std::thread_local! {
+            static LOCAL: LocalInterner = LocalInterner::new();
+        }
+        LOCAL.with(f)
+    }
+
+    /// Get a `KStringBase` for `other`, sharing storage with any previous call for the same
+    /// string.
+    #[must_use]
+    pub fn intern(&self, other: &str) -> KStringBase<RcStr> {
+        if let Some(inline) = KStringBase::try_inline(other) {
+            return inline;
+        }
+
+        KStringBase::from_heap(self.share(other))
+    }
+
+    /// Look up, or insert, the shared allocation for `other`, counting the lookup
+    pub(crate) fn share(&self, other: &str) -> RcStr {
+        let mut entries = self.entries.borrow_mut();
+        let mut stats = self.stats.get();
+        let shared = if let Some(existing) = entries.get(other) {
+            stats.hits += 1;
+            existing.clone()
+        } else {
+            stats.misses += 1;
+            let created = RcStr::from_str(other);
+            entries.insert(created.clone());
+            created
+        };
+        self.stats.set(stats);
+        shared
+    }
+
+    /// Lookup counts since creation or the last [`LocalInterner::reset_stats`].
+    ///
+    /// Strings short enough to be inlined bypass the table and are not counted.
+    #[inline]
+    #[must_use]
+    pub fn stats(&self) -> InternStats {
+        self.stats.get()
+    }
+
+    /// Zero the lookup counts.
+    #[inline]
+    pub fn reset_stats(&self) {
+        self.stats.set(InternStats::default());
+    }
+
+    /// Drop every entry no longer referenced outside of the `LocalInterner`, returning how
+    /// many were removed.
+    pub fn purge(&self) -> usize {
+        let mut entries = self.entries.borrow_mut();
+        let before = entries.len();
+        entries.retain(|s| 1 < RcStr::strong_count(s));
+        before - entries.len()
+    }
+
+    /// Forget every entry.
+    ///
+    /// Strings already handed out remain valid but will no longer be shared with future
+    /// calls to [`LocalInterner::intern`].
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+
+    /// Release table capacity not needed for the current entries.
+    pub fn shrink_to_fit(&self) {
+        self.entries.borrow_mut().shrink_to_fit();
+    }
+
+    /// Number of distinct strings currently held.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    /// Returns `true` if no strings are currently held.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.borrow().is_empty()
+    }
+}
+
+/// Lookup counts for a [`LocalInterner`]
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InternStats {
+    /// Lookups that reused an existing entry
+    pub hits: u64,
+    /// Lookups that created a new entry
+    pub misses: u64,
+}
@@ -8,12 +8,14 @@
 //! let literal = kstring::KString::from_static("literal");
 //! // Implicit
 //! let literal = kstring::KString::from("literal");
+//! // Compile-time, inlined when short enough
+//! const LITERAL: kstring::KString = kstring::kstring!("literal");
 //!
 //! // Explicit
 //! let inline = kstring::KString::try_inline("stack").unwrap();
 //! let inline = kstring::KString::from_ref("stack");
 //!
-//! let formatted: kstring::KStringCow = format!("Hello {} and {}", literal, inline).into();
+//! let formatted: kstring::KStringCow = kstring::kformat!("Hello {} and {}", literal, inline).into();
 //! ```
 //!
 //! # Background
@@ -47,13 +49,28 @@
 #[allow(unused_extern_crates)]
 extern crate alloc;
 
+mod builder;
+mod cmp;
+#[cfg(any(feature = "arbitrary", feature = "quickcheck", feature = "proptest"))]
+mod generate;
+mod macros;
 mod stack;
 mod string;
 mod string_cow;
 mod string_ref;
 
 pub mod backend;
+#[cfg(feature = "std")]
+pub mod intern;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "proptest")]
+pub mod strategy;
 
+pub use builder::KStringBuilder;
+pub use stack::CapacityError;
+pub use stack::Drain;
+pub use stack::FromUtf8Error;
 pub use stack::StackString;
 pub use string::*;
 pub use string_cow::*;
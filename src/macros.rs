@@ -0,0 +1,57 @@
+/// Create a [`KString`][crate::KString] from a string literal at compile-time
+///
+/// The string is stored inline if it fits and references the `'static` data otherwise, so it
+/// never allocates and can be used in `const` and `static` items.
+///
+/// For other backends or capacities, see
+/// [`KStringBase::from_static_inline`][crate::KStringBase::from_static_inline].
+///
+/// # Panics
+///
+/// Like [`KStringBase::from_static`][crate::KStringBase::from_static], this fails to compile for
+/// literals of 16 MiB or more on 32-bit targets with the `thin` feature.
+///
+/// # Examples
+///
+/// ```
+/// use kstring::kstring;
+///
+/// const NAME: kstring::KString = kstring!("name");
+/// static DESCRIPTION: kstring::KString =
+///     kstring!("a description that is much too long to be stored inline");
+///
+/// let key = kstring!("key");
+/// assert_eq!(key, "key");
+/// assert_eq!(NAME, "name");
+/// assert!(DESCRIPTION.starts_with("a description"));
+/// ```
+#[macro_export]
+macro_rules! kstring {
+    ($s:expr $(,)?) => {{
+        const KSTRING: $crate::KString = $crate::KString::from_static_inline($s);
+        KSTRING
+    }};
+}
+
+/// Create a [`KString`][crate::KString] using interpolation of runtime expressions
+///
+/// Like [`format!`], except the result is only allocated if it won't fit inline and format
+/// strings without arguments are referenced rather than copied.
+///
+/// See [`KStringBase::from_fmt`][crate::KStringBase::from_fmt].
+///
+/// # Examples
+///
+/// ```
+/// use kstring::kformat;
+///
+/// let id = 5;
+/// let key = kformat!("item-{id}");
+/// assert_eq!(key, "item-5");
+/// ```
+#[macro_export]
+macro_rules! kformat {
+    ($($arg:tt)*) => {
+        $crate::KString::from_fmt(::core::format_args!($($arg)*))
+    };
+}
@@ -0,0 +1,129 @@
+//! Borrow [`KStringCowBase`]s from the input, for `#[serde(with = "kstring::serde::borrow")]`
+//!
+//! [`KStringCowBase`]'s `Deserialize` impl always produces an owned string.  This instead keeps
+//! strings the format hands over as `&'de str`, like unescaped JSON strings, as
+//! [`StorageKind::Borrowed`][crate::StorageKind::Borrowed], only owning strings that had to be
+//! transformed, like escaped ones.
+//!
+//! # Examples
+//!
+//! ```
+//! use kstring::KStringCow;
+//! use kstring::StorageKind;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Entry<'a> {
+//!     #[serde(borrow, with = "kstring::serde::borrow")]
+//!     key: KStringCow<'a>,
+//! }
+//!
+//! let json = r#"{"key": "a-long-key-that-would-not-fit-inline"}"#;
+//! let entry: Entry<'_> = serde_json::from_str(json).unwrap();
+//! assert_eq!(entry.key.storage(), StorageKind::Borrowed);
+//!
+//! let json = r#"{"key": "a-long-key-that-\"needs\"-unescaping"}"#;
+//! let entry: Entry<'_> = serde_json::from_str(json).unwrap();
+//! assert_eq!(entry.key.storage(), StorageKind::Heap);
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::backend::HeapStr;
+use crate::KStringBase;
+use crate::KStringCowBase;
+
+/// Serialize a [`KStringCowBase`], like its `Serialize` impl
+#[inline]
+pub fn serialize<S, B: HeapStr, const N: usize>(
+    value: &KStringCowBase<'_, B, N>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serde::Serialize::serialize(value, serializer)
+}
+
+/// Deserialize a [`KStringCowBase`], borrowing from the input when possible
+pub fn deserialize<'de: 's, 's, D, B: HeapStr, const N: usize>(
+    deserializer: D,
+) -> Result<KStringCowBase<'s, B, N>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_str(CowVisitor(core::marker::PhantomData))
+}
+
+struct CowVisitor<'s, B, const N: usize>(core::marker::PhantomData<(&'s str, B)>);
+
+impl<'de: 's, 's, B: HeapStr, const N: usize> serde::de::Visitor<'de> for CowVisitor<'s, B, N> {
+    type Value = KStringCowBase<'s, B, N>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Self::Value::from_ref(v))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(KStringBase::from_ref(v).into())
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Self::Value::from_string(v))
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match core::str::from_utf8(v) {
+            Ok(s) => Ok(Self::Value::from_ref(s)),
+            Err(_) => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Bytes(v),
+                &self,
+            )),
+        }
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match KStringBase::from_utf8(v) {
+            Ok(s) => Ok(s.into()),
+            Err(_) => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Bytes(v),
+                &self,
+            )),
+        }
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match String::from_utf8(v) {
+            Ok(s) => Ok(Self::Value::from_string(s)),
+            Err(e) => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Bytes(&e.into_bytes()),
+                &self,
+            )),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+//! [`serde`][mod@::serde] helpers for kstring types
+
+pub mod borrow;
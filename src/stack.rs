@@ -1,5 +1,9 @@
+#[cfg(all(feature = "quickcheck", not(feature = "std")))]
+use alloc::boxed::Box;
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
+#[cfg(all(any(feature = "borsh", feature = "bincode"), not(feature = "std")))]
+use alloc::string::ToString;
 use core::fmt;
 
 pub(crate) type Len = u8;
@@ -36,23 +40,19 @@ impl<const CAPACITY: usize> StackString<CAPACITY> {
     /// assert_eq!(s.as_deref(), Some("foo"));
     /// let s = kstring::StackString::<3>::try_new("foobar");
     /// assert_eq!(s, None);
+    ///
+    /// const KEY: Option<kstring::StackString<3>> = kstring::StackString::try_new("key");
+    /// assert_eq!(KEY.as_deref(), Some("key"));
     /// ```
     #[inline]
     #[must_use]
-    pub fn try_new(s: &str) -> Option<Self> {
+    pub const fn try_new(s: &str) -> Option<Self> {
         let () = Self::ASSERT_CAPACITY_FITS_IN_LEN;
         let len = s.len();
         if len <= Self::CAPACITY {
-            #[cfg(feature = "unsafe")]
-            let stack = {
-                unsafe {
-                    // SAFETY: We've confirmed `len` is within size
-                    Self::new_unchecked(s)
-                }
-            };
-            #[cfg(not(feature = "unsafe"))]
-            let stack = { Self::new(s) };
-            Some(stack)
+            let buffer = StrBuffer::copy_from(s);
+            let len = len as u8; // guarded by `Self::CAPACITY`
+            Some(Self { len, buffer })
         } else {
             None
         }
@@ -110,6 +110,116 @@ impl<const CAPACITY: usize> StackString<CAPACITY> {
         }
     }
 
+    /// Create a `StackString` from a slice of bytes, if they are UTF-8 and fit within
+    /// `Self::CAPACITY`
+    ///
+    /// # Errors
+    ///
+    /// Capacity is checked before encoding, so bytes that are both too long and not UTF-8 report
+    /// [`FromUtf8Error::Capacity`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use kstring::FromUtf8Error;
+    ///
+    /// let s = kstring::StackString::<3>::from_utf8(b"foo");
+    /// assert_eq!(s.unwrap(), "foo");
+    ///
+    /// let s = kstring::StackString::<3>::from_utf8(b"foobar");
+    /// assert!(matches!(s, Err(FromUtf8Error::Capacity(_))));
+    ///
+    /// let s = kstring::StackString::<3>::from_utf8(b"fo\xff");
+    /// assert!(matches!(s, Err(FromUtf8Error::Utf8(_))));
+    /// ```
+    #[inline]
+    pub fn from_utf8(bytes: &[u8]) -> Result<Self, FromUtf8Error> {
+        if Self::CAPACITY < bytes.len() {
+            return Err(CapacityError::new(bytes.len(), CAPACITY).into());
+        }
+        let s = core::str::from_utf8(bytes)?;
+        Ok(Self::new(s))
+    }
+
+    /// Create a `StackString` from a slice of bytes, replacing invalid UTF-8 sequences with
+    /// [`U+FFFD REPLACEMENT CHARACTER`][char::REPLACEMENT_CHARACTER]
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the result, including replacement characters, won't fit.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// let s = kstring::StackString::<6>::from_utf8_lossy(b"fo\xffo");
+    /// assert_eq!(s.unwrap(), "fo\u{FFFD}o");
+    ///
+    /// let s = kstring::StackString::<5>::from_utf8_lossy(b"fo\xffo");
+    /// assert_eq!(s.unwrap_err().requested(), 6);
+    /// ```
+    #[inline]
+    pub fn from_utf8_lossy(bytes: &[u8]) -> Result<Self, CapacityError> {
+        const REPLACEMENT: &str = "\u{FFFD}";
+        let len = bytes
+            .utf8_chunks()
+            .map(|chunk| {
+                let invalid = if chunk.invalid().is_empty() {
+                    0
+                } else {
+                    REPLACEMENT.len()
+                };
+                chunk.valid().len() + invalid
+            })
+            .sum();
+        if Self::CAPACITY < len {
+            return Err(CapacityError::new(len, CAPACITY));
+        }
+
+        let mut lossy = Self::EMPTY;
+        for chunk in bytes.utf8_chunks() {
+            lossy.try_push_str(chunk.valid())?;
+            if !chunk.invalid().is_empty() {
+                lossy.try_push_str(REPLACEMENT)?;
+            }
+        }
+        Ok(lossy)
+    }
+
+    /// Create a `StackString` from a slice of bytes without checking that they are UTF-8
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if `bytes` won't fit.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be valid UTF-8, see [`core::str::from_utf8_unchecked`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// let s = unsafe {
+    ///     // SAFETY: Literal is UTF-8
+    ///     kstring::StackString::<3>::from_utf8_unchecked(b"foo")
+    /// };
+    /// assert_eq!(s.unwrap(), "foo");
+    /// ```
+    #[inline]
+    #[cfg(feature = "unsafe")]
+    pub unsafe fn from_utf8_unchecked(bytes: &[u8]) -> Result<Self, CapacityError> {
+        let s = unsafe {
+            // SAFETY: Caller guarantees `bytes` is UTF-8
+            core::str::from_utf8_unchecked(bytes)
+        };
+        Self::try_from(s)
+    }
+
     /// Extracts a string slice containing the entire `StackString`.
     ///
     /// # Examples
@@ -253,84 +363,471 @@ impl<const CAPACITY: usize> StackString<CAPACITY> {
             self.len = new_len as u8; // guardedd by `fn len()`
         }
     }
-}
 
-impl<const CAPACITY: usize> Default for StackString<CAPACITY> {
-    fn default() -> Self {
-        Self::empty()
+    /// Appends the given [`char`] to the end of this `StackString`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`], leaving `self` unchanged, if `ch` won't fit.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// let mut s = kstring::StackString::<3>::try_new("ab").unwrap();
+    ///
+    /// assert!(s.try_push('c').is_ok());
+    /// assert!(s.try_push('d').is_err());
+    ///
+    /// assert_eq!(s, "abc");
+    /// ```
+    #[inline]
+    pub fn try_push(&mut self, ch: char) -> Result<(), CapacityError> {
+        self.try_push_str(ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Appends a given string slice onto the end of this `StackString`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`], leaving `self` unchanged, if `s` won't fit.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// let mut s = kstring::StackString::<6>::try_new("foo").unwrap();
+    ///
+    /// assert!(s.try_push_str("bar").is_ok());
+    /// assert!(s.try_push_str("baz").is_err());
+    ///
+    /// assert_eq!(s, "foobar");
+    /// ```
+    #[inline]
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), CapacityError> {
+        let len = self.len();
+        self.splice(len, len, s)
+    }
+
+    /// Removes the last character from the `StackString` and returns it.
+    ///
+    /// Returns [`None`] if this `StackString` is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// let mut s = kstring::StackString::<4>::try_new("abč").unwrap();
+    ///
+    /// assert_eq!(s.pop(), Some('č'));
+    /// assert_eq!(s.pop(), Some('b'));
+    /// assert_eq!(s.pop(), Some('a'));
+    ///
+    /// assert_eq!(s.pop(), None);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.chars().next_back()?;
+        self.len -= ch.len_utf8() as u8; // guarded by `fn len()`
+        Some(ch)
+    }
+
+    /// Inserts a character into this `StackString` at a byte position.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`], leaving `self` unchanged, if `ch` won't fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the `StackString`'s length, or if it does not lie on a
+    /// [`char`] boundary.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// let mut s = kstring::StackString::<3>::try_new("ac").unwrap();
+    ///
+    /// assert!(s.insert(1, 'b').is_ok());
+    /// assert!(s.insert(0, '_').is_err());
+    ///
+    /// assert_eq!(s, "abc");
+    /// ```
+    #[inline]
+    pub fn insert(&mut self, idx: usize, ch: char) -> Result<(), CapacityError> {
+        self.replace_range(idx..idx, ch.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Removes a [`char`] from this `StackString` at a byte position and returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than or equal to the `StackString`'s length, or if it does not
+    /// lie on a [`char`] boundary.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// let mut s = kstring::StackString::<4>::try_new("abč").unwrap();
+    ///
+    /// assert_eq!(s.remove(0), 'a');
+    /// assert_eq!(s.remove(1), 'č');
+    /// assert_eq!(s.remove(0), 'b');
+    /// ```
+    #[inline]
+    pub fn remove(&mut self, idx: usize) -> char {
+        let Some(ch) = self[idx..].chars().next() else {
+            panic!("cannot remove a char from the end of a string");
+        };
+        let _ = self.splice(idx, idx + ch.len_utf8(), ""); // shrinking always fits
+        ch
+    }
+
+    /// Retains only the characters specified by the predicate.
+    ///
+    /// In other words, remove all characters `c` such that `f(c)` returns `false`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// let mut s = kstring::StackString::<9>::try_new("f_o_ob_ar").unwrap();
+    ///
+    /// s.retain(|c| c != '_');
+    ///
+    /// assert_eq!(s, "foobar");
+    /// ```
+    #[inline]
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, mut f: F) {
+        let mut retained = Self::EMPTY;
+        for ch in self.chars().filter(|ch| f(*ch)) {
+            let _ = retained.try_push(ch); // a subset always fits
+        }
+        *self = retained;
+    }
+
+    /// Removes the specified range from the `StackString`, returning the removed characters as
+    /// an iterator.
+    ///
+    /// The range is removed even if the iterator is not consumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point or end point do not lie on a [`char`] boundary, or if they're
+    /// out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// let mut s = kstring::StackString::<16>::try_new("α is alpha").unwrap();
+    /// let beta_offset = s.find('i').unwrap_or(s.len());
+    ///
+    /// let t: String = s.drain(..beta_offset).collect();
+    /// assert_eq!(t, "α ");
+    /// assert_eq!(s, "is alpha");
+    ///
+    /// s.drain(..);
+    /// assert_eq!(s, "");
+    /// ```
+    #[inline]
+    pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<CAPACITY> {
+        let core::ops::Range { start, end } = self.char_range(range);
+        let drained = Drain {
+            drained: Self::new(&self[start..end]),
+            front: 0,
+            back: end - start,
+        };
+        let _ = self.splice(start, end, ""); // shrinking always fits
+        drained
+    }
+
+    /// Removes the specified range in the `StackString`, and replaces it with the given string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`], leaving `self` unchanged, if the result won't fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point or end point do not lie on a [`char`] boundary, or if they're
+    /// out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// let mut s = kstring::StackString::<16>::try_new("α is alpha").unwrap();
+    /// let beta_offset = s.find('i').unwrap_or(s.len());
+    ///
+    /// assert!(s.replace_range(..beta_offset, "Α ").is_ok());
+    /// assert_eq!(s, "Α is alpha");
+    ///
+    /// assert!(s.replace_range(.., "Α is capital alpha").is_err());
+    /// assert_eq!(s, "Α is alpha");
+    /// ```
+    #[inline]
+    pub fn replace_range<R: core::ops::RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        replace_with: &str,
+    ) -> Result<(), CapacityError> {
+        let core::ops::Range { start, end } = self.char_range(range);
+        self.splice(start, end, replace_with)
+    }
+
+    fn char_range<R: core::ops::RangeBounds<usize>>(&self, range: R) -> core::ops::Range<usize> {
+        use core::ops::Bound;
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n.checked_add(1).expect("range start overflowed"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n.checked_add(1).expect("range end overflowed"),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => self.len(),
+        };
+        assert!(
+            start <= end,
+            "range start {start} is larger than range end {end}"
+        );
+        assert!(
+            self.is_char_boundary(start),
+            "{start} is not a char boundary"
+        );
+        assert!(self.is_char_boundary(end), "{end} is not a char boundary");
+        start..end
+    }
+
+    /// Replace `start..end` with `s`, where both lie on a `char` boundary
+    fn splice(&mut self, start: usize, end: usize, s: &str) -> Result<(), CapacityError> {
+        let len = self.len();
+        let new_len = len - (end - start) + s.len();
+        if Self::CAPACITY < new_len {
+            return Err(CapacityError::new(new_len, CAPACITY));
+        }
+        self.buffer.0.copy_within(end..len, start + s.len());
+        self.buffer.write(start, s);
+        self.len = new_len as u8; // guarded by `Self::CAPACITY`
+        Ok(())
+    }
+
+    /// Create a `StackString` by having `read` fill its buffer with `len` bytes of UTF-8
+    ///
+    /// `read` is only called when `len` fits, so decoders avoid any intermediate buffer.
+    #[cfg(any(feature = "borsh", feature = "bincode"))]
+    pub(crate) fn read_from<E>(
+        len: usize,
+        read: impl FnOnce(&mut [u8]) -> Result<(), E>,
+    ) -> Result<Result<Self, FromUtf8Error>, E> {
+        if Self::CAPACITY < len {
+            return Ok(Err(CapacityError::new(len, CAPACITY).into()));
+        }
+        let mut s = Self::EMPTY;
+        let bytes = &mut s.buffer.0[..len];
+        read(bytes)?;
+        if let Err(err) = core::str::from_utf8(bytes) {
+            return Ok(Err(err.into()));
+        }
+        s.len = len as u8; // guarded by `Self::CAPACITY`
+        Ok(Ok(s))
     }
 }
 
-impl<const CAPACITY: usize> core::ops::Deref for StackString<CAPACITY> {
-    type Target = str;
+/// A draining iterator for [`StackString`]
+///
+/// This struct is created by [`StackString::drain`].
+#[derive(Clone)]
+pub struct Drain<const CAPACITY: usize> {
+    drained: StackString<CAPACITY>,
+    front: usize,
+    back: usize,
+}
 
+impl<const CAPACITY: usize> Drain<CAPACITY> {
+    /// Returns the remaining (sub)string of this iterator as a slice.
     #[inline]
-    fn deref(&self) -> &str {
-        self.as_str()
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.drained[self.front..self.back]
     }
 }
 
-impl<const CAPACITY: usize> Eq for StackString<CAPACITY> {}
+impl<const CAPACITY: usize> Iterator for Drain<CAPACITY> {
+    type Item = char;
 
-impl<const C1: usize, const C2: usize> PartialEq<StackString<C1>> for StackString<C2> {
     #[inline]
-    fn eq(&self, other: &StackString<C1>) -> bool {
-        PartialEq::eq(self.as_str(), other.as_str())
+    fn next(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next()?;
+        self.front += ch.len_utf8();
+        Some(ch)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.as_str().chars().size_hint()
     }
 }
 
-impl<const CAPACITY: usize> PartialEq<str> for StackString<CAPACITY> {
+impl<const CAPACITY: usize> DoubleEndedIterator for Drain<CAPACITY> {
     #[inline]
-    fn eq(&self, other: &str) -> bool {
-        PartialEq::eq(self.as_str(), other)
+    fn next_back(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        self.back -= ch.len_utf8();
+        Some(ch)
     }
 }
 
-impl<const CAPACITY: usize> PartialEq<&str> for StackString<CAPACITY> {
+impl<const CAPACITY: usize> core::iter::FusedIterator for Drain<CAPACITY> {}
+
+impl<const CAPACITY: usize> fmt::Debug for Drain<CAPACITY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Drain").field(&self.as_str()).finish()
+    }
+}
+
+/// The string did not fit within a [`StackString`]'s capacity
+///
+/// # Examples
+///
+/// ```
+/// let err = kstring::StackString::<3>::try_from("foobar").unwrap_err();
+/// assert_eq!(err.requested(), 6);
+/// assert_eq!(err.capacity(), 3);
+/// assert_eq!(err.to_string(), "string of 6 bytes exceeds capacity of 3");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CapacityError {
+    requested: usize,
+    capacity: usize,
+}
+
+impl CapacityError {
+    #[inline]
+    pub(crate) const fn new(requested: usize, capacity: usize) -> Self {
+        Self {
+            requested,
+            capacity,
+        }
+    }
+
+    /// The length, in bytes, that was requested
     #[inline]
-    fn eq(&self, other: &&str) -> bool {
-        PartialEq::eq(self.as_str(), *other)
+    #[must_use]
+    pub const fn requested(&self) -> usize {
+        self.requested
+    }
+
+    /// The most bytes that could have been stored
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "string of {} bytes exceeds capacity of {}",
+            self.requested, self.capacity
+        )
     }
 }
 
-impl<const CAPACITY: usize> PartialEq<String> for StackString<CAPACITY> {
+// `rkyv` reports capacity errors through `rancor::Source`, which requires `Error`, even without
+// `std`
+#[cfg(any(feature = "std", feature = "rkyv"))]
+impl core::error::Error for CapacityError {}
+
+/// The bytes could not be converted into a [`StackString`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FromUtf8Error {
+    /// The bytes are longer than the capacity
+    Capacity(CapacityError),
+    /// The bytes are not valid UTF-8
+    Utf8(core::str::Utf8Error),
+}
+
+impl From<CapacityError> for FromUtf8Error {
     #[inline]
-    fn eq(&self, other: &String) -> bool {
-        PartialEq::eq(self.as_str(), other.as_str())
+    fn from(other: CapacityError) -> Self {
+        Self::Capacity(other)
     }
 }
 
-impl<const CAPACITY: usize> Ord for StackString<CAPACITY> {
+impl From<core::str::Utf8Error> for FromUtf8Error {
     #[inline]
-    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-        self.as_str().cmp(other.as_str())
+    fn from(other: core::str::Utf8Error) -> Self {
+        Self::Utf8(other)
     }
 }
 
-impl<const C1: usize, const C2: usize> PartialOrd<StackString<C1>> for StackString<C2> {
+impl fmt::Display for FromUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Capacity(err) => err.fmt(f),
+            Self::Utf8(err) => err.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for FromUtf8Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Capacity(err) => Some(err),
+            Self::Utf8(err) => Some(err),
+        }
+    }
+}
+
+impl<const CAPACITY: usize> Default for StackString<CAPACITY> {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<const CAPACITY: usize> core::ops::Deref for StackString<CAPACITY> {
+    type Target = str;
+
     #[inline]
-    fn partial_cmp(&self, other: &StackString<C1>) -> Option<core::cmp::Ordering> {
-        self.as_str().partial_cmp(other.as_str())
+    fn deref(&self) -> &str {
+        self.as_str()
     }
 }
 
-impl<const CAPACITY: usize> PartialOrd<str> for StackString<CAPACITY> {
+impl<const CAPACITY: usize> Eq for StackString<CAPACITY> {}
+
+impl<const C1: usize, const C2: usize> PartialEq<StackString<C1>> for StackString<C2> {
     #[inline]
-    fn partial_cmp(&self, other: &str) -> Option<core::cmp::Ordering> {
-        self.as_str().partial_cmp(other)
+    fn eq(&self, other: &StackString<C1>) -> bool {
+        PartialEq::eq(self.as_str(), other.as_str())
     }
 }
 
-impl<const CAPACITY: usize> PartialOrd<&str> for StackString<CAPACITY> {
+impl<const CAPACITY: usize> Ord for StackString<CAPACITY> {
     #[inline]
-    fn partial_cmp(&self, other: &&str) -> Option<core::cmp::Ordering> {
-        self.as_str().partial_cmp(other)
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
     }
 }
 
-impl<const CAPACITY: usize> PartialOrd<String> for StackString<CAPACITY> {
+impl<const C1: usize, const C2: usize> PartialOrd<StackString<C1>> for StackString<C2> {
     #[inline]
-    fn partial_cmp(&self, other: &String) -> Option<core::cmp::Ordering> {
+    fn partial_cmp(&self, other: &StackString<C1>) -> Option<core::cmp::Ordering> {
         self.as_str().partial_cmp(other.as_str())
     }
 }
@@ -356,6 +853,45 @@ impl<const CAPACITY: usize> fmt::Display for StackString<CAPACITY> {
     }
 }
 
+impl<const CAPACITY: usize> TryFrom<&str> for StackString<CAPACITY> {
+    type Error = CapacityError;
+
+    #[inline]
+    fn try_from(other: &str) -> Result<Self, Self::Error> {
+        Self::try_new(other).ok_or(CapacityError::new(other.len(), CAPACITY))
+    }
+}
+
+impl<const CAPACITY: usize> TryFrom<String> for StackString<CAPACITY> {
+    type Error = CapacityError;
+
+    #[inline]
+    fn try_from(other: String) -> Result<Self, Self::Error> {
+        Self::try_from(other.as_str())
+    }
+}
+
+impl<const CAPACITY: usize> TryFrom<char> for StackString<CAPACITY> {
+    type Error = CapacityError;
+
+    #[inline]
+    fn try_from(other: char) -> Result<Self, Self::Error> {
+        Self::try_from(&*other.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl<const CAPACITY: usize> fmt::Write for StackString<CAPACITY> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.try_push(c).map_err(|_| fmt::Error)
+    }
+}
+
 impl<const CAPACITY: usize> AsRef<str> for StackString<CAPACITY> {
     #[inline]
     fn as_ref(&self) -> &str {
@@ -393,6 +929,257 @@ impl<const CAPACITY: usize> alloc::borrow::Borrow<str> for StackString<CAPACITY>
     }
 }
 
+#[cfg(feature = "serde")]
+impl<const CAPACITY: usize> serde::Serialize for StackString<CAPACITY> {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const CAPACITY: usize> serde::Deserialize<'de> for StackString<CAPACITY> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StackStringVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct StackStringVisitor<const CAPACITY: usize>;
+
+#[cfg(feature = "serde")]
+impl<const CAPACITY: usize> serde::de::Visitor<'_> for StackStringVisitor<CAPACITY> {
+    type Value = StackString<CAPACITY>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "a string of at most {CAPACITY} bytes")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Self::Value::try_new(v).ok_or_else(|| E::invalid_length(v.len(), &self))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match Self::Value::from_utf8(v) {
+            Ok(s) => Ok(s),
+            Err(FromUtf8Error::Capacity(_)) => Err(E::invalid_length(v.len(), &self)),
+            Err(FromUtf8Error::Utf8(_)) => {
+                Err(E::invalid_value(serde::de::Unexpected::Bytes(v), &self))
+            }
+        }
+    }
+}
+
+/// Archived as an [`ArchivedString`][rkyv::string::ArchivedString], like
+/// [`KStringBase`][crate::KStringBase].
+///
+/// Deserializing fails with a [`CapacityError`] if the archived string is longer than `CAPACITY`.
+#[cfg(feature = "rkyv")]
+impl<const CAPACITY: usize> rkyv::Archive for StackString<CAPACITY> {
+    type Archived = rkyv::string::ArchivedString;
+    type Resolver = rkyv::string::StringResolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+        rkyv::string::ArchivedString::resolve_from_str(self.as_str(), resolver, out);
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<const CAPACITY: usize, S> rkyv::Serialize<S> for StackString<CAPACITY>
+where
+    S: rkyv::rancor::Fallible + rkyv::ser::Allocator + rkyv::ser::Writer + ?Sized,
+    S::Error: rkyv::rancor::Source,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        rkyv::string::ArchivedString::serialize_from_str(self.as_str(), serializer)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<const CAPACITY: usize, D> rkyv::Deserialize<StackString<CAPACITY>, D>
+    for rkyv::string::ArchivedString
+where
+    D: rkyv::rancor::Fallible + ?Sized,
+    D::Error: rkyv::rancor::Source,
+{
+    #[inline]
+    fn deserialize(&self, _deserializer: &mut D) -> Result<StackString<CAPACITY>, D::Error> {
+        StackString::try_new(self.as_str())
+            .ok_or_else(|| rkyv::rancor::Source::new(CapacityError::new(self.len(), CAPACITY)))
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<const CAPACITY: usize> PartialEq<StackString<CAPACITY>> for rkyv::string::ArchivedString {
+    #[inline]
+    fn eq(&self, other: &StackString<CAPACITY>) -> bool {
+        PartialEq::eq(self.as_str(), other.as_str())
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<const CAPACITY: usize> PartialEq<rkyv::string::ArchivedString> for StackString<CAPACITY> {
+    #[inline]
+    fn eq(&self, other: &rkyv::string::ArchivedString) -> bool {
+        PartialEq::eq(self.as_str(), other.as_str())
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<const CAPACITY: usize> borsh::BorshSerialize for StackString<CAPACITY> {
+    #[inline]
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.as_str().serialize(writer)
+    }
+}
+
+/// Fails with [`ErrorKind::InvalidData`][borsh::io::ErrorKind::InvalidData] if the string is
+/// longer than `CAPACITY`.
+#[cfg(feature = "borsh")]
+impl<const CAPACITY: usize> borsh::BorshDeserialize for StackString<CAPACITY> {
+    #[inline]
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let len = u32::deserialize_reader(reader)? as usize;
+        Self::read_from(len, |bytes| reader.read_exact(bytes))?.map_err(borsh_error)
+    }
+}
+
+#[cfg(feature = "borsh")]
+pub(crate) fn borsh_error(err: FromUtf8Error) -> borsh::io::Error {
+    borsh::io::Error::new(borsh::io::ErrorKind::InvalidData, err.to_string())
+}
+
+#[cfg(feature = "bincode")]
+impl<const CAPACITY: usize> bincode::Encode for StackString<CAPACITY> {
+    #[inline]
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        self.as_str().encode(encoder)
+    }
+}
+
+/// Fails with [`DecodeError::OtherString`][bincode::error::DecodeError::OtherString] if the
+/// string is longer than `CAPACITY`.
+#[cfg(feature = "bincode")]
+impl<Context, const CAPACITY: usize> bincode::Decode<Context> for StackString<CAPACITY> {
+    #[inline]
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        let len = bincode_len(decoder)?;
+        decoder.claim_bytes_read(len)?;
+        Self::read_from(len, |bytes| {
+            bincode::de::read::Reader::read(decoder.reader(), bytes)
+        })?
+        .map_err(bincode_error)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<'de, Context, const CAPACITY: usize> bincode::BorrowDecode<'de, Context>
+    for StackString<CAPACITY>
+{
+    #[inline]
+    fn borrow_decode<D: bincode::de::BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        bincode::Decode::decode(decoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+pub(crate) fn bincode_error(err: FromUtf8Error) -> bincode::error::DecodeError {
+    match err {
+        FromUtf8Error::Capacity(err) => bincode::error::DecodeError::OtherString(err.to_string()),
+        FromUtf8Error::Utf8(inner) => bincode::error::DecodeError::Utf8 { inner },
+    }
+}
+
+/// Decode the length prefix that `bincode` writes before a `str`
+#[cfg(feature = "bincode")]
+pub(crate) fn bincode_len<D: bincode::de::Decoder>(
+    decoder: &mut D,
+) -> Result<usize, bincode::error::DecodeError> {
+    let len = <u64 as bincode::Decode<D::Context>>::decode(decoder)?;
+    usize::try_from(len).map_err(|_| bincode::error::DecodeError::OutsideUsizeRange(len))
+}
+
+/// Covers short strings and ones that exactly fill `CAPACITY`.
+#[cfg(feature = "arbitrary")]
+impl<'a, const CAPACITY: usize> arbitrary::Arbitrary<'a> for StackString<CAPACITY> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let shape = *u.choose(&crate::generate::Shape::ALL)?;
+        let s = u.arbitrary()?;
+        Ok(shape.stack_string(s))
+    }
+}
+
+/// Covers short strings and ones that exactly fill `CAPACITY`.
+#[cfg(feature = "quickcheck")]
+impl<const CAPACITY: usize> quickcheck::Arbitrary for StackString<CAPACITY> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let shape = *g
+            .choose(&crate::generate::Shape::ALL)
+            .expect("`Shape::ALL` is non-empty");
+        let s = <String as quickcheck::Arbitrary>::arbitrary(g);
+        shape.stack_string(&s)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let s = String::from(self.as_str());
+        Box::new(quickcheck::Arbitrary::shrink(&s).filter_map(|s| Self::try_new(&s)))
+    }
+}
+
+/// See [`strategy::stack_string`][crate::strategy::stack_string].
+#[cfg(feature = "proptest")]
+impl<const CAPACITY: usize> proptest::arbitrary::Arbitrary for StackString<CAPACITY> {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::boxed(crate::strategy::stack_string())
+    }
+}
+
+#[cfg(feature = "get-size")]
+impl<const CAPACITY: usize> get_size::GetSize for StackString<CAPACITY> {}
+
+#[cfg(feature = "datasize")]
+impl<const CAPACITY: usize> datasize::DataSize for StackString<CAPACITY> {
+    const IS_DYNAMIC: bool = false;
+    const STATIC_HEAP_SIZE: usize = 0;
+
+    #[inline]
+    fn estimate_heap_size(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(feature = "deepsize")]
+impl<const CAPACITY: usize> deepsize::DeepSizeOf for StackString<CAPACITY> {
+    #[inline]
+    fn deep_size_of_children(&self, _context: &mut deepsize::Context) -> usize {
+        0
+    }
+}
+
 #[derive(Copy, Clone)]
 #[repr(transparent)]
 pub(crate) struct StrBuffer<const CAPACITY: usize>([u8; CAPACITY]);
@@ -416,6 +1203,25 @@ impl<const CAPACITY: usize> StrBuffer<CAPACITY> {
         buffer
     }
 
+    /// Copy `s` to the front of the buffer, panicking if it doesn't fit
+    #[inline]
+    pub(crate) const fn copy_from(s: &str) -> Self {
+        let mut buffer = Self::empty();
+        let (front, _) = buffer.0.split_at_mut(s.len());
+        front.copy_from_slice(s.as_bytes());
+        buffer
+    }
+
+    #[inline]
+    pub(crate) fn write(&mut self, start: usize, s: &str) {
+        let end = start + s.len();
+        if let Some(buffer) = self.0.get_mut(start..end) {
+            buffer.copy_from_slice(s.as_bytes());
+        } else {
+            panic!("`{s}` at {start} is larger than capacity {CAPACITY}");
+        }
+    }
+
     #[inline]
     #[cfg(not(feature = "unsafe"))]
     pub(crate) fn as_str(&self, len: usize) -> &str {
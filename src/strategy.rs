@@ -0,0 +1,65 @@
+//! [`proptest`][mod@proptest] strategies for kstring types
+//!
+//! Each strategy deliberately covers every storage variant, including strings that exactly fill
+//! the inline capacity and ones that spill just past it.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::prelude::*;
+//!
+//! type Key = kstring::KStringBase<kstring::backend::BoxedStr, 15>;
+//!
+//! proptest! {
+//!     fn roundtrip(key in kstring::strategy::kstring::<kstring::backend::BoxedStr, 15>()) {
+//!         prop_assert_eq!(Key::from_ref(&key), key);
+//!     }
+//! }
+//! # roundtrip();
+//! ```
+
+#[cfg(not(feature = "std"))]
+use alloc::string::String;
+
+use proptest::prelude::*;
+
+use crate::backend::HeapStr;
+use crate::generate::Shape;
+use crate::generate::STATICS;
+use crate::KStringBase;
+use crate::KStringCowBase;
+use crate::KStringRef;
+use crate::StackString;
+
+/// Generate a [`KStringBase`] stored as a `'static` singleton, inline, or on the heap
+pub fn kstring<B: HeapStr + 'static, const N: usize>() -> impl Strategy<Value = KStringBase<B, N>> {
+    (
+        prop::sample::select(&Shape::ALL[..]),
+        any::<String>(),
+        any::<usize>(),
+    )
+        .prop_map(|(shape, s, index)| shape.kstring(&s, index))
+}
+
+/// Generate a [`KStringCowBase`], covering borrowed data along with everything from [`kstring`]
+pub fn kstring_cow<B: HeapStr + 'static, const N: usize>(
+) -> impl Strategy<Value = KStringCowBase<'static, B, N>> {
+    prop_oneof![
+        3 => kstring::<B, N>().prop_map(KStringCowBase::from),
+        1 => prop::sample::select(STATICS).prop_map(KStringCowBase::from_ref),
+    ]
+}
+
+/// Generate a [`KStringRef`], either `'static` or borrowed
+pub fn kstring_ref() -> impl Strategy<Value = KStringRef<'static>> {
+    prop_oneof![
+        prop::sample::select(STATICS).prop_map(KStringRef::from_static),
+        prop::sample::select(STATICS).prop_map(KStringRef::from_ref),
+    ]
+}
+
+/// Generate a [`StackString`], including ones that exactly fill `CAPACITY`
+pub fn stack_string<const CAPACITY: usize>() -> impl Strategy<Value = StackString<CAPACITY>> {
+    (prop::sample::select(&Shape::ALL[..]), any::<String>())
+        .prop_map(|(shape, s)| shape.stack_string(&s))
+}
@@ -1,5 +1,9 @@
+#[cfg(all(feature = "quickcheck", not(feature = "std")))]
+use alloc::boxed::Box;
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
+#[cfg(all(any(feature = "serde", feature = "borsh"), not(feature = "std")))]
+use alloc::vec::Vec;
 use alloc::{borrow::Cow, fmt};
 
 use crate::stack::StackString;
@@ -11,16 +15,100 @@ pub(crate) type StdString = String;
 /// A UTF-8 encoded, immutable string.
 pub type KString = KStringBase<crate::backend::DefaultStr>;
 
+/// A UTF-8 encoded, immutable string, inlining strings up to the size of a `Box<str>` (15 bytes
+/// on 64-bit).
+pub type KStringAligned<B = crate::backend::DefaultStr> = KStringBase<B, ALIGNED_CAPACITY>;
+
+/// A UTF-8 encoded, immutable string, inlining strings up to the size of a `String` (22 bytes on
+/// 64-bit).
+pub type KStringMaxInline<B = crate::backend::DefaultStr> = KStringBase<B, MAX_CAPACITY>;
+
+const LEN_SIZE: usize = size_of::<crate::stack::Len>();
+
+const TAG_SIZE: usize = size_of::<u8>();
+
+pub(crate) const MAX_CAPACITY: usize = size_of::<StdString>() - LEN_SIZE - TAG_SIZE;
+
+// Performance seems to slow down when trying to occupy all of the padding left by `String`'s
+// discriminant.  The question is whether faster len=1-16 "allocations" outweighs going to the heap
+// for len=17-22.
+pub(crate) const ALIGNED_CAPACITY: usize = size_of::<crate::backend::BoxedStr>() - LEN_SIZE;
+
+// A thin payload leaves no padding to trade off, so fill the two words
+#[allow(unused)]
+pub(crate) const THIN_CAPACITY: usize = size_of::<crate::backend::BoxedStr>() - LEN_SIZE - TAG_SIZE;
+
+#[cfg(feature = "thin")]
+pub(crate) const CAPACITY: usize = THIN_CAPACITY;
+#[cfg(all(not(feature = "thin"), feature = "max_inline"))]
+pub(crate) const CAPACITY: usize = MAX_CAPACITY;
+#[cfg(all(not(feature = "thin"), not(feature = "max_inline")))]
+pub(crate) const CAPACITY: usize = ALIGNED_CAPACITY;
+
+#[cfg(not(feature = "thin"))]
+static_assertions::assert_eq_size!(KString, StdString);
+#[cfg(feature = "thin")]
+static_assertions::assert_eq_size!(KString, crate::backend::BoxedStr);
+static_assertions::assert_eq_size!(KStringAligned<crate::backend::BoxedStr>, StdString);
+static_assertions::assert_eq_size!(KStringMaxInline<crate::backend::BoxedStr>, StdString);
+// Both the safe and `unsafe` layouts fit in a `String` for the standard backends
+static_assertions::assert_eq_size!(KStringBase<crate::backend::BoxedStr, 0>, StdString);
+static_assertions::assert_eq_size!(KStringMaxInline<crate::backend::ArcStr>, StdString);
+static_assertions::assert_eq_size!(KStringMaxInline<crate::backend::RcStr>, StdString);
+static_assertions::assert_eq_size!(
+    KStringBase<crate::backend::BoxedStr, { MAX_CAPACITY + 1 }>,
+    [usize; 4]
+);
+// Leave room for `Option<KString>` fields
+static_assertions::assert_eq_size!(Option<KString>, KString);
+static_assertions::assert_eq_size!(Option<KStringMaxInline>, KStringMaxInline);
+
 /// A UTF-8 encoded, immutable string.
+///
+/// - `B` is the [`HeapStr`][crate::backend::HeapStr] used for strings that don't fit inline
+/// - `N` is the longest string, in bytes, stored inline.  This must be at most 255.
+///
+/// # Examples
+///
+/// ```
+/// // Trade a larger `KStringBase` for avoiding the heap with longer strings
+/// type Key = kstring::KStringBase<kstring::backend::BoxedStr, 30>;
+/// let key = Key::from_ref("a key that is 30 bytes long...");
+/// assert_eq!(key.len(), Key::INLINE_CAPACITY);
+/// assert!(Key::try_inline(&key).is_some());
+/// ```
+///
+/// The inline length is stored in a byte, so larger capacities are rejected at compile-time:
+/// ```compile_fail
+/// type Key = kstring::KStringBase<kstring::backend::BoxedStr, 256>;
+/// let key = Key::from_static("");
+/// ```
 #[derive(Clone)]
 #[repr(transparent)]
-pub struct KStringBase<B> {
-    inner: KStringInner<B>,
+pub struct KStringBase<B, const N: usize = CAPACITY> {
+    inner: KStringInner<B, N>,
 }
 
-impl<B> KStringBase<B> {
+impl<B, const N: usize> KStringBase<B, N> {
     pub const EMPTY: Self = Self::from_static("");
 
+    /// The longest string, in bytes, that is stored inline rather than on the heap
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let longest = "0".repeat(kstring::KString::INLINE_CAPACITY);
+    /// assert!(kstring::KString::try_inline(&longest).is_some());
+    ///
+    /// let too_long = "0".repeat(kstring::KString::INLINE_CAPACITY + 1);
+    /// assert!(kstring::KString::try_inline(&too_long).is_none());
+    /// ```
+    pub const INLINE_CAPACITY: usize = N;
+    const ASSERT_CAPACITY_FITS_IN_LEN: () = assert!(
+        N <= crate::stack::Len::MAX as usize,
+        "inline capacity `N` must be <= 255"
+    );
+
     /// Create a new empty `KStringBase`.
     #[inline]
     #[must_use]
@@ -29,27 +117,76 @@ impl<B> KStringBase<B> {
     }
 
     /// Create a reference to a `'static` data.
+    ///
+    /// # Panics
+    ///
+    /// With the `thin` feature, the length is packed into `size_of::<usize>() - 1` bytes, so this
+    /// panics for strings of 16 MiB or more on 32-bit targets.  `From<&'static str>` copies those
+    /// to the heap instead.
     #[inline]
     #[must_use]
     pub const fn from_static(other: &'static str) -> Self {
+        let () = Self::ASSERT_CAPACITY_FITS_IN_LEN;
         Self {
             inner: KStringInner::from_static(other),
         }
     }
 
+    /// Create a `KStringBase` from `'static` data, inlining it if it fits.
+    ///
+    /// This is what [`kstring!`][crate::kstring!] uses to pick a representation at compile-time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// const KEY: kstring::KString = kstring::KString::from_static_inline("key");
+    /// assert_eq!(KEY, "key");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_static_inline(other: &'static str) -> Self {
+        match StackString::try_new(other) {
+            Some(inline) => Self::from_inline(inline),
+            None => Self::from_static(other),
+        }
+    }
+
     /// Create an inline string, if possible
     #[inline]
     #[must_use]
-    pub fn try_inline(other: &str) -> Option<Self> {
-        KStringInner::try_inline(other).map(|inner| Self { inner })
+    pub const fn try_inline(other: &str) -> Option<Self> {
+        let () = Self::ASSERT_CAPACITY_FITS_IN_LEN;
+        match StackString::try_new(other) {
+            Some(inline) => Some(Self::from_inline(inline)),
+            None => None,
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn from_inline(other: StackString<N>) -> Self {
+        let () = Self::ASSERT_CAPACITY_FITS_IN_LEN;
+        Self {
+            inner: KStringInner::from_inline(other),
+        }
     }
 }
 
-impl<B: crate::backend::HeapStr> KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> KStringBase<B, N> {
+    /// Create an owned `KStringBase` from an existing heap string, sharing its storage.
+    #[inline]
+    #[must_use]
+    pub fn from_heap(other: B) -> Self {
+        let () = Self::ASSERT_CAPACITY_FITS_IN_LEN;
+        Self {
+            inner: KStringInner::from_heap(other),
+        }
+    }
+
     /// Create an owned `KStringBase`.
     #[inline]
     #[must_use]
     pub fn from_boxed(other: crate::backend::BoxedStr) -> Self {
+        let () = Self::ASSERT_CAPACITY_FITS_IN_LEN;
         Self {
             inner: KStringInner::from_boxed(other),
         }
@@ -59,6 +196,7 @@ impl<B: crate::backend::HeapStr> KStringBase<B> {
     #[inline]
     #[must_use]
     pub fn from_string(other: StdString) -> Self {
+        let () = Self::ASSERT_CAPACITY_FITS_IN_LEN;
         Self {
             inner: KStringInner::from_string(other),
         }
@@ -68,11 +206,102 @@ impl<B: crate::backend::HeapStr> KStringBase<B> {
     #[inline]
     #[must_use]
     pub fn from_ref(other: &str) -> Self {
+        let () = Self::ASSERT_CAPACITY_FITS_IN_LEN;
         Self {
             inner: KStringInner::from_ref(other),
         }
     }
 
+    /// Create a `KStringBase` from formatted text, allocating only if it won't fit inline.
+    ///
+    /// Literal-only arguments become a `'static` reference.  See also [`kformat!`][crate::kformat!].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a formatting trait implementation returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = kstring::KString::from_fmt(format_args!("{}-{}", "key", 1));
+    /// assert_eq!(s, "key-1");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+        if let Some(s) = args.as_str() {
+            return Self::from(s);
+        }
+
+        let mut builder = crate::KStringBuilder::<B, N>::new();
+        fmt::Write::write_fmt(&mut builder, args)
+            .expect("a formatting trait implementation returned an error");
+        builder.finish()
+    }
+
+    /// Create an owned `KStringBase` from a slice of bytes, if they are UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`core::str::Utf8Error`] if `bytes` are not UTF-8.  Unlike
+    /// [`StackString::from_utf8`], there is no capacity limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = kstring::KString::from_utf8(b"foo").unwrap();
+    /// assert_eq!(s, "foo");
+    /// assert!(kstring::KString::from_utf8(b"fo\xff").is_err());
+    /// ```
+    #[inline]
+    pub fn from_utf8(bytes: &[u8]) -> Result<Self, core::str::Utf8Error> {
+        core::str::from_utf8(bytes).map(Self::from_ref)
+    }
+
+    /// Create an owned `KStringBase` from a slice of bytes, replacing invalid UTF-8 sequences
+    /// with [`U+FFFD REPLACEMENT CHARACTER`][char::REPLACEMENT_CHARACTER].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = kstring::KString::from_utf8_lossy(b"fo\xffo");
+    /// assert_eq!(s, "fo\u{FFFD}o");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_utf8_lossy(bytes: &[u8]) -> Self {
+        match StdString::from_utf8_lossy(bytes) {
+            Cow::Borrowed(s) => Self::from_ref(s),
+            Cow::Owned(s) => Self::from_string(s),
+        }
+    }
+
+    /// Create an owned `KStringBase` from a slice of bytes without checking that they are UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// `bytes` must be valid UTF-8, see [`core::str::from_utf8_unchecked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let s = unsafe {
+    ///     // SAFETY: Literal is UTF-8
+    ///     kstring::KString::from_utf8_unchecked(b"foo")
+    /// };
+    /// assert_eq!(s, "foo");
+    /// ```
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "unsafe")]
+    pub unsafe fn from_utf8_unchecked(bytes: &[u8]) -> Self {
+        let s = unsafe {
+            // SAFETY: Caller guarantees `bytes` is UTF-8
+            core::str::from_utf8_unchecked(bytes)
+        };
+        Self::from_ref(s)
+    }
+
     /// Get a reference to the `KStringBase`.
     #[inline]
     #[must_use]
@@ -80,6 +309,48 @@ impl<B: crate::backend::HeapStr> KStringBase<B> {
         self.inner.as_ref()
     }
 
+    /// Where the string's data is stored
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kstring::{KString, StorageKind};
+    ///
+    /// assert_eq!(KString::from_static("key").storage(), StorageKind::Static);
+    /// assert_eq!(KString::from_ref("key").storage(), StorageKind::Inline);
+    /// let long = "key".repeat(KString::INLINE_CAPACITY);
+    /// assert_eq!(KString::from_ref(&long).storage(), StorageKind::Heap);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn storage(&self) -> StorageKind {
+        self.inner.storage()
+    }
+
+    /// Bytes of string data stored on the heap
+    ///
+    /// This is the string's length when [`StorageKind::Heap`] and `0` otherwise.  It does not
+    /// include allocator or backend overhead, like reference counts, nor does it account for the
+    /// data being shared between clones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kstring::KString;
+    ///
+    /// assert_eq!(KString::from_ref("key").heap_bytes(), 0);
+    /// let long = "key".repeat(KString::INLINE_CAPACITY);
+    /// assert_eq!(KString::from_ref(&long).heap_bytes(), long.len());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn heap_bytes(&self) -> usize {
+        match self.storage() {
+            StorageKind::Heap => self.len(),
+            StorageKind::Static | StorageKind::Inline | StorageKind::Borrowed => 0,
+        }
+    }
+
     /// Extracts a string slice containing the entire `KStringBase`.
     #[inline]
     #[must_use]
@@ -87,6 +358,26 @@ impl<B: crate::backend::HeapStr> KStringBase<B> {
         self.inner.as_str()
     }
 
+    /// Whether both point at the same string data, like [`Arc::ptr_eq`][alloc::sync::Arc::ptr_eq]
+    ///
+    /// This holds for clones of a `'static` string and of a shared heap string (e.g. with the `arc`
+    /// feature).  An inline string only points at its own data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kstring::KString;
+    ///
+    /// let key = KString::from_static("key");
+    /// assert!(KString::ptr_eq(&key, &key.clone()));
+    /// assert!(!KString::ptr_eq(&key, &KString::from_ref("key")));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        core::ptr::eq(this.as_str(), other.as_str())
+    }
+
     /// Convert to a mutable string type, cloning the data if necessary.
     #[inline]
     #[must_use]
@@ -109,88 +400,123 @@ impl<B: crate::backend::HeapStr> KStringBase<B> {
     }
 }
 
-impl<B: crate::backend::HeapStr> core::ops::Deref for KStringBase<B> {
-    type Target = str;
-
+#[cfg(feature = "std")]
+impl<B, const N: usize> KStringBase<B, N> {
+    /// Create a `KStringBase` that shares its storage with every other interned copy of `other`
+    ///
+    /// This is available whatever `B` is, like through [`KString`], though the result is always
+    /// backed by [`ArcStr`][crate::backend::ArcStr] so it can be shared.  Entries stay in the
+    /// table until [`Interner::purge`][crate::intern::Interner::purge] is called.
+    ///
+    /// See [`Interner::global`][crate::intern::Interner::global].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let first = kstring::KString::intern("a key too long to inline");
+    /// let second = kstring::KString::intern("a key too long to inline");
+    /// assert_eq!(first.as_ptr(), second.as_ptr());
+    /// ```
     #[inline]
-    fn deref(&self) -> &str {
-        self.as_str()
-    }
-}
-
-impl<B: crate::backend::HeapStr> Eq for KStringBase<B> {}
+    #[must_use]
+    pub fn intern(other: &str) -> KStringBase<crate::backend::ArcStr, N> {
+        if let Some(inline) = KStringBase::try_inline(other) {
+            return inline;
+        }
 
-impl<B: crate::backend::HeapStr> PartialEq<Self> for KStringBase<B> {
+        KStringBase::from_heap(crate::intern::Interner::global().share(other))
+    }
+
+    /// Create a `KStringBase` that shares its storage with every other copy of `other`
+    /// interned on this thread
+    ///
+    /// Like [`KStringBase::intern`], this is available whatever `B` is, though the result is
+    /// always backed by [`RcStr`][crate::backend::RcStr].
+    ///
+    /// See [`LocalInterner::with_thread_local`][crate::intern::LocalInterner::with_thread_local].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let first = kstring::KString::intern_local("a key too long to inline");
+    /// let second = kstring::KString::intern_local("a key too long to inline");
+    /// assert_eq!(first.as_ptr(), second.as_ptr());
+    /// ```
     #[inline]
-    fn eq(&self, other: &Self) -> bool {
-        PartialEq::eq(self.as_str(), other.as_str())
-    }
-}
+    #[must_use]
+    pub fn intern_local(other: &str) -> KStringBase<crate::backend::RcStr, N> {
+        if let Some(inline) = KStringBase::try_inline(other) {
+            return inline;
+        }
 
-impl<B: crate::backend::HeapStr> PartialEq<str> for KStringBase<B> {
-    #[inline]
-    fn eq(&self, other: &str) -> bool {
-        PartialEq::eq(self.as_str(), other)
+        KStringBase::from_heap(crate::intern::LocalInterner::with_thread_local(
+            |interner| interner.share(other),
+        ))
     }
 }
 
-impl<B: crate::backend::HeapStr> PartialEq<&str> for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> core::ops::Deref for KStringBase<B, N> {
+    type Target = str;
+
     #[inline]
-    fn eq(&self, other: &&str) -> bool {
-        PartialEq::eq(self.as_str(), *other)
+    fn deref(&self) -> &str {
+        self.as_str()
     }
 }
 
-impl<B: crate::backend::HeapStr> PartialEq<String> for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> Eq for KStringBase<B, N> {}
+
+impl<B: crate::backend::HeapStr, const N: usize> PartialEq<Self> for KStringBase<B, N> {
     #[inline]
-    fn eq(&self, other: &StdString) -> bool {
-        PartialEq::eq(self.as_str(), other.as_str())
+    fn eq(&self, other: &Self) -> bool {
+        // Skip comparing the bytes of shared strings, like clones of an `Arc`
+        Self::ptr_eq(self, other) || PartialEq::eq(self.as_str(), other.as_str())
     }
 }
 
-impl<B: crate::backend::HeapStr> Ord for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> Ord for KStringBase<B, N> {
     #[inline]
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.as_str().cmp(other.as_str())
     }
 }
 
-impl<B: crate::backend::HeapStr> PartialOrd for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> PartialOrd for KStringBase<B, N> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<B: crate::backend::HeapStr> core::hash::Hash for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> core::hash::Hash for KStringBase<B, N> {
     #[inline]
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.as_str().hash(state);
     }
 }
 
-impl<B: crate::backend::HeapStr> fmt::Debug for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> fmt::Debug for KStringBase<B, N> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.as_str().fmt(f)
     }
 }
 
-impl<B: crate::backend::HeapStr> fmt::Display for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> fmt::Display for KStringBase<B, N> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Display::fmt(self.as_str(), f)
     }
 }
 
-impl<B: crate::backend::HeapStr> AsRef<str> for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> AsRef<str> for KStringBase<B, N> {
     #[inline]
     fn as_ref(&self) -> &str {
         self.as_str()
     }
 }
 
-impl<B: crate::backend::HeapStr> AsRef<[u8]> for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> AsRef<[u8]> for KStringBase<B, N> {
     #[inline]
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
@@ -198,7 +524,7 @@ impl<B: crate::backend::HeapStr> AsRef<[u8]> for KStringBase<B> {
 }
 
 #[cfg(feature = "std")]
-impl<B: crate::backend::HeapStr> AsRef<std::ffi::OsStr> for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> AsRef<std::ffi::OsStr> for KStringBase<B, N> {
     #[inline]
     fn as_ref(&self) -> &std::ffi::OsStr {
         (**self).as_ref()
@@ -206,91 +532,105 @@ impl<B: crate::backend::HeapStr> AsRef<std::ffi::OsStr> for KStringBase<B> {
 }
 
 #[cfg(feature = "std")]
-impl<B: crate::backend::HeapStr> AsRef<std::path::Path> for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> AsRef<std::path::Path> for KStringBase<B, N> {
     #[inline]
     fn as_ref(&self) -> &std::path::Path {
         std::path::Path::new(self)
     }
 }
 
-impl<B: crate::backend::HeapStr> alloc::borrow::Borrow<str> for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> alloc::borrow::Borrow<str> for KStringBase<B, N> {
     #[inline]
     fn borrow(&self) -> &str {
         self.as_str()
     }
 }
 
-impl<B: crate::backend::HeapStr> Default for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> Default for KStringBase<B, N> {
     #[inline]
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<KStringRef<'s>> for KStringBase<B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<KStringRef<'s>> for KStringBase<B, N> {
     #[inline]
     fn from(other: KStringRef<'s>) -> Self {
-        other.to_owned()
+        other.inner.to_owned()
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<&'s KStringRef<'s>> for KStringBase<B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<&'s KStringRef<'s>>
+    for KStringBase<B, N>
+{
     #[inline]
     fn from(other: &'s KStringRef<'s>) -> Self {
-        other.to_owned()
+        other.inner.to_owned()
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<KStringCowBase<'s, B>> for KStringBase<B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<KStringCowBase<'s, B, N>>
+    for KStringBase<B, N>
+{
     #[inline]
-    fn from(other: KStringCowBase<'s, B>) -> Self {
+    fn from(other: KStringCowBase<'s, B, N>) -> Self {
         other.into_owned()
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<&'s KStringCowBase<'s, B>> for KStringBase<B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<&'s KStringCowBase<'s, B, N>>
+    for KStringBase<B, N>
+{
     #[inline]
-    fn from(other: &'s KStringCowBase<'s, B>) -> Self {
+    fn from(other: &'s KStringCowBase<'s, B, N>) -> Self {
         other.clone().into_owned()
     }
 }
 
-impl<B: crate::backend::HeapStr> From<StdString> for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> From<StdString> for KStringBase<B, N> {
     #[inline]
     fn from(other: StdString) -> Self {
         Self::from_string(other)
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<&'s StdString> for KStringBase<B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<&'s StdString> for KStringBase<B, N> {
     #[inline]
     fn from(other: &'s StdString) -> Self {
         Self::from_ref(other)
     }
 }
 
-impl<B: crate::backend::HeapStr> From<crate::backend::BoxedStr> for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> From<crate::backend::BoxedStr>
+    for KStringBase<B, N>
+{
     #[inline]
     fn from(other: crate::backend::BoxedStr) -> Self {
         Self::from_boxed(other)
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<&'s crate::backend::BoxedStr> for KStringBase<B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<&'s crate::backend::BoxedStr>
+    for KStringBase<B, N>
+{
     #[inline]
     fn from(other: &'s crate::backend::BoxedStr) -> Self {
         Self::from_ref(other)
     }
 }
 
-impl<B: crate::backend::HeapStr> From<&'static str> for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> From<&'static str> for KStringBase<B, N> {
     #[inline]
     fn from(other: &'static str) -> Self {
-        Self::from_static(other)
+        if KStringInner::<B, N>::fits_static(other) {
+            Self::from_static(other)
+        } else {
+            Self::from_heap(B::from_str(other))
+        }
     }
 }
 
-impl<B: crate::backend::HeapStr> core::str::FromStr for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> core::str::FromStr for KStringBase<B, N> {
     type Err = core::convert::Infallible;
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -299,7 +639,7 @@ impl<B: crate::backend::HeapStr> core::str::FromStr for KStringBase<B> {
 }
 
 #[cfg(feature = "serde")]
-impl<B: crate::backend::HeapStr> serde::Serialize for KStringBase<B> {
+impl<B: crate::backend::HeapStr, const N: usize> serde::Serialize for KStringBase<B, N> {
     #[inline]
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -310,21 +650,32 @@ impl<B: crate::backend::HeapStr> serde::Serialize for KStringBase<B> {
 }
 
 #[cfg(feature = "serde")]
-impl<'de, B: crate::backend::HeapStr> serde::Deserialize<'de> for KStringBase<B> {
+impl<'de, B: crate::backend::HeapStr, const N: usize> serde::Deserialize<'de>
+    for KStringBase<B, N>
+{
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_string(StringVisitor(core::marker::PhantomData))
+        // Asking for a `str` rather than a `String` lets formats hand over their buffer, so short
+        // strings are copied straight into inline storage without a temporary allocation
+        deserializer.deserialize_str(StringVisitor(core::marker::PhantomData))
+    }
+
+    fn deserialize_in_place<D>(deserializer: D, place: &mut Self) -> Result<(), D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(InPlaceVisitor(place))
     }
 }
 
 #[cfg(feature = "serde")]
-struct StringVisitor<B>(core::marker::PhantomData<B>);
+struct StringVisitor<B, const N: usize>(core::marker::PhantomData<B>);
 
 #[cfg(feature = "serde")]
-impl<B: crate::backend::HeapStr> serde::de::Visitor<'_> for StringVisitor<B> {
-    type Value = KStringBase<B>;
+impl<B: crate::backend::HeapStr, const N: usize> serde::de::Visitor<'_> for StringVisitor<B, N> {
+    type Value = KStringBase<B, N>;
 
     fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         formatter.write_str("a string")
@@ -371,32 +722,378 @@ impl<B: crate::backend::HeapStr> serde::de::Visitor<'_> for StringVisitor<B> {
     }
 }
 
+#[cfg(feature = "serde")]
+struct InPlaceVisitor<'p, B, const N: usize>(&'p mut KStringBase<B, N>);
+
+#[cfg(feature = "serde")]
+impl<B: crate::backend::HeapStr, const N: usize> serde::de::Visitor<'_>
+    for InPlaceVisitor<'_, B, N>
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        // Keep the existing storage, like a shared `Arc`, when the value is unchanged
+        if self.0.as_str() != v {
+            *self.0 = KStringBase::from_ref(v);
+        }
+        Ok(())
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        if self.0.as_str() != v {
+            *self.0 = KStringBase::from_string(v);
+        }
+        Ok(())
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match core::str::from_utf8(v) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Bytes(v),
+                &self,
+            )),
+        }
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match String::from_utf8(v) {
+            Ok(s) => self.visit_string(s),
+            Err(e) => Err(serde::de::Error::invalid_value(
+                serde::de::Unexpected::Bytes(&e.into_bytes()),
+                &self,
+            )),
+        }
+    }
+}
+
+/// Archived as an [`ArchivedString`][rkyv::string::ArchivedString], the same as a `String`.
+///
+/// Short strings stay inline in the archive, though rkyv's inline capacity is independent of `N`.
+/// The archived string compares and hashes like a `str`, so archived maps can be looked up with a
+/// `&str` without deserializing.
+#[cfg(feature = "rkyv")]
+impl<B: crate::backend::HeapStr, const N: usize> rkyv::Archive for KStringBase<B, N> {
+    type Archived = rkyv::string::ArchivedString;
+    type Resolver = rkyv::string::StringResolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+        rkyv::string::ArchivedString::resolve_from_str(self.as_str(), resolver, out);
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<B: crate::backend::HeapStr, const N: usize, S> rkyv::Serialize<S> for KStringBase<B, N>
+where
+    S: rkyv::rancor::Fallible + rkyv::ser::Allocator + rkyv::ser::Writer + ?Sized,
+    S::Error: rkyv::rancor::Source,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        rkyv::string::ArchivedString::serialize_from_str(self.as_str(), serializer)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<B: crate::backend::HeapStr, const N: usize, D> rkyv::Deserialize<KStringBase<B, N>, D>
+    for rkyv::string::ArchivedString
+where
+    D: rkyv::rancor::Fallible + ?Sized,
+{
+    #[inline]
+    fn deserialize(&self, _deserializer: &mut D) -> Result<KStringBase<B, N>, D::Error> {
+        Ok(KStringBase::from_ref(self.as_str()))
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<B: crate::backend::HeapStr, const N: usize> PartialEq<KStringBase<B, N>>
+    for rkyv::string::ArchivedString
+{
+    #[inline]
+    fn eq(&self, other: &KStringBase<B, N>) -> bool {
+        PartialEq::eq(self.as_str(), other.as_str())
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<B: crate::backend::HeapStr, const N: usize> PartialEq<rkyv::string::ArchivedString>
+    for KStringBase<B, N>
+{
+    #[inline]
+    fn eq(&self, other: &rkyv::string::ArchivedString) -> bool {
+        PartialEq::eq(self.as_str(), other.as_str())
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<B: crate::backend::HeapStr, const N: usize> PartialOrd<KStringBase<B, N>>
+    for rkyv::string::ArchivedString
+{
+    #[inline]
+    fn partial_cmp(&self, other: &KStringBase<B, N>) -> Option<core::cmp::Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<B: crate::backend::HeapStr, const N: usize> PartialOrd<rkyv::string::ArchivedString>
+    for KStringBase<B, N>
+{
+    #[inline]
+    fn partial_cmp(&self, other: &rkyv::string::ArchivedString) -> Option<core::cmp::Ordering> {
+        PartialOrd::partial_cmp(self.as_str(), other.as_str())
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<B: crate::backend::HeapStr, const N: usize> borsh::BorshSerialize for KStringBase<B, N> {
+    #[inline]
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.as_str().serialize(writer)
+    }
+}
+
+/// Strings that fit within `N` are read straight into inline storage.
+#[cfg(feature = "borsh")]
+impl<B: crate::backend::HeapStr, const N: usize> borsh::BorshDeserialize for KStringBase<B, N> {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let len = u32::deserialize_reader(reader)? as usize;
+        if len <= N {
+            let inline = StackString::read_from(len, |bytes| reader.read_exact(bytes))?
+                .map_err(crate::stack::borsh_error)?;
+            return Ok(Self::from_inline(inline));
+        }
+
+        // Grow the buffer as data arrives so a corrupt length can't force a huge allocation
+        let mut bytes = Vec::new();
+        while bytes.len() < len {
+            let start = bytes.len();
+            let end = len.min(start + start.max(4096));
+            bytes.resize(end, 0);
+            reader.read_exact(&mut bytes[start..])?;
+        }
+        let s = String::from_utf8(bytes)
+            .map_err(|err| crate::stack::borsh_error(err.utf8_error().into()))?;
+        Ok(Self::from_string(s))
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<B: crate::backend::HeapStr, const N: usize> bincode::Encode for KStringBase<B, N> {
+    #[inline]
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        self.as_str().encode(encoder)
+    }
+}
+
+/// Strings that fit within `N` are read straight into inline storage.
+#[cfg(feature = "bincode")]
+impl<Context, B: crate::backend::HeapStr, const N: usize> bincode::Decode<Context>
+    for KStringBase<B, N>
+{
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        let len = crate::stack::bincode_len(decoder)?;
+        decoder.claim_bytes_read(len)?;
+        if len <= N {
+            let inline = StackString::read_from(len, |bytes| {
+                bincode::de::read::Reader::read(decoder.reader(), bytes)
+            })?
+            .map_err(crate::stack::bincode_error)?;
+            return Ok(Self::from_inline(inline));
+        }
+
+        let mut bytes = alloc::vec![0; len];
+        bincode::de::read::Reader::read(decoder.reader(), &mut bytes)?;
+        let s = String::from_utf8(bytes).map_err(|err| bincode::error::DecodeError::Utf8 {
+            inner: err.utf8_error(),
+        })?;
+        Ok(Self::from_string(s))
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<'de, Context, B: crate::backend::HeapStr, const N: usize> bincode::BorrowDecode<'de, Context>
+    for KStringBase<B, N>
+{
+    #[inline]
+    fn borrow_decode<D: bincode::de::BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        bincode::Decode::decode(decoder)
+    }
+}
+
+/// Covers `'static` singletons, inline strings, strings that exactly fill `N` and heap strings.
+#[cfg(feature = "arbitrary")]
+impl<'a, B: crate::backend::HeapStr, const N: usize> arbitrary::Arbitrary<'a>
+    for KStringBase<B, N>
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let shape = *u.choose(&crate::generate::Shape::ALL)?;
+        let index = u.arbitrary()?;
+        let s = u.arbitrary()?;
+        Ok(shape.kstring(s, index))
+    }
+}
+
+/// Covers `'static` singletons, inline strings, strings that exactly fill `N` and heap strings.
+#[cfg(feature = "quickcheck")]
+impl<B: crate::backend::HeapStr + 'static, const N: usize> quickcheck::Arbitrary
+    for KStringBase<B, N>
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let shape = *g
+            .choose(&crate::generate::Shape::ALL)
+            .expect("`Shape::ALL` is non-empty");
+        let s = <String as quickcheck::Arbitrary>::arbitrary(g);
+        let index = <usize as quickcheck::Arbitrary>::arbitrary(g);
+        shape.kstring(&s, index)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let s = String::from(self.as_str());
+        Box::new(quickcheck::Arbitrary::shrink(&s).map(Self::from_string))
+    }
+}
+
+/// See [`strategy::kstring`][crate::strategy::kstring].
+#[cfg(feature = "proptest")]
+impl<B: crate::backend::HeapStr + 'static, const N: usize> proptest::arbitrary::Arbitrary
+    for KStringBase<B, N>
+{
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::boxed(crate::strategy::kstring())
+    }
+}
+
+#[cfg(feature = "get-size")]
+impl<B: crate::backend::HeapStr, const N: usize> get_size::GetSize for KStringBase<B, N> {
+    #[inline]
+    fn get_heap_size(&self) -> usize {
+        self.inner.heap_size()
+    }
+}
+
+/// Splits shared allocations between their handles, like `get-size`, rather than counting
+/// `Arc`s as zero
+#[cfg(feature = "datasize")]
+impl<B: crate::backend::HeapStr, const N: usize> datasize::DataSize for KStringBase<B, N> {
+    const IS_DYNAMIC: bool = true;
+    const STATIC_HEAP_SIZE: usize = 0;
+
+    #[inline]
+    fn estimate_heap_size(&self) -> usize {
+        self.inner.heap_size()
+    }
+}
+
+/// Counts heap strings through `B`, so the `Context` counts shared `Arc`/`Rc` strings once
+#[cfg(feature = "deepsize")]
+impl<B: crate::backend::HeapStr + deepsize::DeepSizeOf, const N: usize> deepsize::DeepSizeOf
+    for KStringBase<B, N>
+{
+    #[inline]
+    fn deep_size_of_children(&self, context: &mut deepsize::Context) -> usize {
+        self.inner
+            .as_heap()
+            .map(|s| s.deep_size_of_children(context))
+            .unwrap_or(0)
+    }
+}
+
+/// Where a string's data is stored, see [`KStringBase::storage`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StorageKind {
+    /// References `'static` data
+    Static,
+    /// Stored within the value itself
+    Inline,
+    /// Owned on the heap by a [`HeapStr`][crate::backend::HeapStr]
+    Heap,
+    /// References data owned elsewhere
+    Borrowed,
+}
+
 use inner::KStringInner;
 
 #[cfg(not(feature = "unsafe"))]
 mod inner {
-    use super::*;
+    use super::{Cow, KStringRef, StackString, StdString, StorageKind};
 
-    pub(super) enum KStringInner<B> {
-        Singleton(&'static str),
-        Inline(StackString<CAPACITY>),
-        Owned(B),
-    }
+    // Mirrors the `unsafe` layout: `repr(u8)` leads every variant with the discriminant, letting
+    // `Inline` start right after it, and the unused discriminant values give `Option` a niche.
+    //
+    // The one difference is with the `thin` layout, which packs a `&'static str` around the
+    // discriminant.  Here it always takes two full words, which only costs space when `B` is
+    // pointer-sized.
+    #[repr(u8)]
+    pub(super) enum KStringInner<B, const N: usize> {
+        Singleton(&'static str) = 0,
+        Inline(StackString<N>) = 1,
+        Owned(B) = u8::MAX,
+    }
+
+    impl<B, const N: usize> KStringInner<B, N> {
+        /// Whether [`KStringInner::from_static`] can reference `other`
+        #[inline]
+        pub(super) const fn fits_static(_other: &str) -> bool {
+            true
+        }
 
-    impl<B> KStringInner<B> {
         /// Create a reference to a `'static` data.
         #[inline]
-        pub const fn from_static(other: &'static str) -> Self {
+        pub(super) const fn from_static(other: &'static str) -> Self {
             Self::Singleton(other)
         }
 
         #[inline]
-        pub fn try_inline(other: &str) -> Option<Self> {
-            StackString::try_new(other).map(Self::Inline)
+        pub(super) const fn from_inline(other: StackString<N>) -> Self {
+            Self::Inline(other)
+        }
+
+        #[inline]
+        pub(super) const fn storage(&self) -> StorageKind {
+            match self {
+                Self::Singleton(_) => StorageKind::Static,
+                Self::Inline(_) => StorageKind::Inline,
+                Self::Owned(_) => StorageKind::Heap,
+            }
         }
     }
 
-    impl<B: crate::backend::HeapStr> KStringInner<B> {
+    impl<B: crate::backend::HeapStr, const N: usize> KStringInner<B, N> {
+        #[inline]
+        pub(super) fn from_heap(other: B) -> Self {
+            Self::Owned(other)
+        }
+
         #[inline]
         pub(super) fn from_boxed(other: crate::backend::BoxedStr) -> Self {
             #[allow(clippy::useless_conversion)]
@@ -405,18 +1102,18 @@ mod inner {
 
         #[inline]
         pub(super) fn from_string(other: StdString) -> Self {
-            if (0..=CAPACITY).contains(&other.len()) {
-                let inline = { StackString::new(other.as_str()) };
+            if (0..=N).contains(&other.len()) {
+                let inline = StackString::new(other.as_str());
                 Self::Inline(inline)
             } else {
-                Self::from_boxed(other.into_boxed_str())
+                Self::Owned(B::from_string(other))
             }
         }
 
         #[inline]
         pub(super) fn from_ref(other: &str) -> Self {
-            if (0..=CAPACITY).contains(&other.len()) {
-                let inline = { StackString::new(other) };
+            if (0..=N).contains(&other.len()) {
+                let inline = StackString::new(other);
                 Self::Inline(inline)
             } else {
                 Self::Owned(B::from_str(other))
@@ -441,6 +1138,24 @@ mod inner {
             }
         }
 
+        #[cfg(any(feature = "get-size", feature = "datasize"))]
+        #[inline]
+        pub(super) fn heap_size(&self) -> usize {
+            match self {
+                Self::Singleton { .. } | Self::Inline(_) => 0,
+                Self::Owned(s) => s.heap_size(),
+            }
+        }
+
+        #[cfg(feature = "deepsize")]
+        #[inline]
+        pub(super) fn as_heap(&self) -> Option<&B> {
+            match self {
+                Self::Singleton { .. } | Self::Inline(_) => None,
+                Self::Owned(s) => Some(s),
+            }
+        }
+
         #[inline]
         pub(super) fn into_boxed_str(self) -> crate::backend::BoxedStr {
             match self {
@@ -469,7 +1184,7 @@ mod inner {
     //
     // My only guess is that the `clone()` calls we delegate to are just that much bigger than
     // `as_str()` that, when combined with a jump table, is blowing the icache, slowing things down.
-    impl<B: Clone> Clone for KStringInner<B> {
+    impl<B: Clone, const N: usize> Clone for KStringInner<B, N> {
         fn clone(&self) -> Self {
             match self {
                 Self::Singleton(s) => Self::Singleton(s),
@@ -478,172 +1193,149 @@ mod inner {
             }
         }
     }
-
-    #[allow(unused)]
-    const LEN_SIZE: usize = core::mem::size_of::<crate::stack::Len>();
-
-    #[allow(unused)]
-    const TAG_SIZE: usize = core::mem::size_of::<u8>();
-
-    #[allow(unused)]
-    const MAX_CAPACITY: usize =
-        core::mem::size_of::<crate::string::StdString>() - TAG_SIZE - LEN_SIZE;
-
-    // Performance seems to slow down when trying to occupy all of the padding left by `String`'s
-    // discriminant.  The question is whether faster len=1-16 "allocations" outweighs going to the heap
-    // for len=17-22.
-    #[allow(unused)]
-    const ALIGNED_CAPACITY: usize = core::mem::size_of::<crate::backend::DefaultStr>() - LEN_SIZE;
-
-    #[cfg(feature = "max_inline")]
-    const CAPACITY: usize = MAX_CAPACITY;
-    #[cfg(not(feature = "max_inline"))]
-    const CAPACITY: usize = ALIGNED_CAPACITY;
 }
 
 #[cfg(feature = "unsafe")]
 mod inner {
-    use super::{Cow, KStringRef, StackString, StdString};
-
-    #[repr(C)]
-    pub(super) union KStringInner<B> {
-        tag: TagVariant,
-        singleton: SingletonVariant,
-        owned: core::mem::ManuallyDrop<OwnedVariant<B>>,
-        inline: InlineVariant,
-    }
+    use super::{Cow, KStringRef, StackString, StdString, StorageKind};
+
+    // `repr(u8)` lays each variant out as a `repr(C)` struct led by the discriminant, so every
+    // payload can be sized on its own rather than padded out to a common `Target`, while the
+    // unused discriminant values give `Option` a niche.
+    #[repr(u8)]
+    pub(super) enum KStringInner<B, const N: usize> {
+        Singleton {
+            len: SingletonLen,
+            ptr: SingletonPtr,
+        } = 0,
+        Inline(StackString<N>) = 1,
+        Owned(B) = u8::MAX,
+    }
+
+    impl<B, const N: usize> KStringInner<B, N> {
+        /// Whether [`KStringInner::from_static`] can reference `other`
+        #[inline]
+        pub(super) const fn fits_static(other: &str) -> bool {
+            fits_singleton(other)
+        }
 
-    impl<B> KStringInner<B> {
         /// Create a reference to a `'static` data.
         #[inline]
         pub(super) const fn from_static(other: &'static str) -> Self {
-            Self {
-                singleton: SingletonVariant::new(other),
-            }
+            let (len, ptr) = pack_singleton(other);
+            Self::Singleton { len, ptr }
         }
 
         #[inline]
-        pub(super) fn try_inline(other: &str) -> Option<Self> {
-            StackString::try_new(other).map(|inline| Self {
-                inline: InlineVariant::new(inline),
-            })
+        pub(super) const fn from_inline(other: StackString<N>) -> Self {
+            Self::Inline(other)
         }
 
         #[inline]
-        const fn tag(&self) -> Tag {
-            unsafe {
-                // SAFETY: `tag` is in the same spot in each variant
-                self.tag.tag
+        pub(super) const fn storage(&self) -> StorageKind {
+            match self {
+                Self::Singleton { .. } => StorageKind::Static,
+                Self::Inline(_) => StorageKind::Inline,
+                Self::Owned(_) => StorageKind::Heap,
             }
         }
     }
 
-    impl<B: crate::backend::HeapStr> KStringInner<B> {
+    impl<B: crate::backend::HeapStr, const N: usize> KStringInner<B, N> {
+        #[inline]
+        pub(super) fn from_heap(other: B) -> Self {
+            Self::Owned(other)
+        }
+
         #[inline]
         pub(super) fn from_boxed(other: crate::backend::BoxedStr) -> Self {
             #[allow(clippy::useless_conversion)]
             let payload = B::from_boxed_str(other);
-            Self {
-                owned: core::mem::ManuallyDrop::new(OwnedVariant::new(payload)),
-            }
+            Self::from_heap(payload)
         }
 
         #[inline]
         pub(super) fn from_string(other: StdString) -> Self {
-            if (0..=CAPACITY).contains(&other.len()) {
+            if (0..=N).contains(&other.len()) {
                 let payload = unsafe {
                     // SAFETY: range check ensured this is always safe
                     StackString::new_unchecked(other.as_str())
                 };
-                Self {
-                    inline: InlineVariant::new(payload),
-                }
+                Self::Inline(payload)
             } else {
-                Self::from_boxed(other.into_boxed_str())
+                Self::from_heap(B::from_string(other))
             }
         }
 
         #[inline]
         pub(super) fn from_ref(other: &str) -> Self {
-            if (0..=CAPACITY).contains(&other.len()) {
+            if (0..=N).contains(&other.len()) {
                 let payload = unsafe {
                     // SAFETY: range check ensured this is always safe
                     StackString::new_unchecked(other)
                 };
-                Self {
-                    inline: InlineVariant::new(payload),
-                }
+                Self::Inline(payload)
             } else {
                 #[allow(clippy::useless_conversion)]
                 let payload = B::from_str(other);
-                Self {
-                    owned: core::mem::ManuallyDrop::new(OwnedVariant::new(payload)),
-                }
+                Self::from_heap(payload)
             }
         }
 
         #[inline]
         pub(super) fn as_ref(&self) -> KStringRef<'_> {
-            let tag = self.tag();
-            unsafe {
-                // SAFETY: `tag` ensures access to correct variant
-                if tag.is_singleton() {
-                    KStringRef::from_static(self.singleton.payload)
-                } else if tag.is_owned() {
-                    KStringRef::from_ref(self.owned.payload.as_str())
-                } else {
-                    debug_assert!(tag.is_inline());
-                    KStringRef::from_ref(self.inline.payload.as_str())
-                }
+            match self {
+                Self::Singleton { len, ptr } => KStringRef::from_static(singleton(len, ptr)),
+                Self::Inline(s) => KStringRef::from_ref(s.as_str()),
+                Self::Owned(s) => KStringRef::from_ref(s.as_str()),
             }
         }
 
         #[inline]
         pub(super) fn as_str(&self) -> &str {
-            let tag = self.tag();
-            unsafe {
-                // SAFETY: `tag` ensures access to correct variant
-                if tag.is_singleton() {
-                    self.singleton.payload
-                } else if tag.is_owned() {
-                    self.owned.payload.as_str()
-                } else {
-                    debug_assert!(tag.is_inline());
-                    self.inline.payload.as_str()
-                }
+            match self {
+                Self::Singleton { len, ptr } => singleton(len, ptr),
+                Self::Inline(s) => s.as_str(),
+                Self::Owned(s) => s.as_str(),
+            }
+        }
+
+        #[cfg(any(feature = "get-size", feature = "datasize"))]
+        #[inline]
+        pub(super) fn heap_size(&self) -> usize {
+            match self {
+                Self::Singleton { .. } | Self::Inline(_) => 0,
+                Self::Owned(s) => s.heap_size(),
+            }
+        }
+
+        #[cfg(feature = "deepsize")]
+        #[inline]
+        pub(super) fn as_heap(&self) -> Option<&B> {
+            match self {
+                Self::Singleton { .. } | Self::Inline(_) => None,
+                Self::Owned(s) => Some(s),
             }
         }
 
         #[inline]
         pub(super) fn into_boxed_str(self) -> crate::backend::BoxedStr {
-            let tag = self.tag();
-            unsafe {
-                // SAFETY: `tag` ensures access to correct variant
-                if tag.is_singleton() {
-                    crate::backend::BoxedStr::from(self.singleton.payload)
-                } else if tag.is_owned() {
-                    crate::backend::BoxedStr::from(self.owned.payload.as_str())
-                } else {
-                    debug_assert!(tag.is_inline());
-                    crate::backend::BoxedStr::from(self.inline.payload.as_ref())
+            match self {
+                Self::Singleton { len, ptr } => {
+                    crate::backend::BoxedStr::from(singleton(&len, &ptr))
                 }
+                Self::Inline(s) => crate::backend::BoxedStr::from(s.as_ref()),
+                Self::Owned(s) => crate::backend::BoxedStr::from(s.as_str()),
             }
         }
 
         /// Convert to a Cow str
         #[inline]
         pub(super) fn into_cow_str(self) -> Cow<'static, str> {
-            let tag = self.tag();
-            unsafe {
-                // SAFETY: `tag` ensures access to correct variant
-                if tag.is_singleton() {
-                    Cow::Borrowed(self.singleton.payload)
-                } else if tag.is_owned() {
-                    Cow::Owned(self.owned.payload.as_str().into())
-                } else {
-                    debug_assert!(tag.is_inline());
-                    Cow::Owned(self.inline.payload.as_str().into())
-                }
+            match self {
+                Self::Singleton { len, ptr } => Cow::Borrowed(singleton(&len, &ptr)),
+                Self::Inline(s) => Cow::Owned(s.as_str().into()),
+                Self::Owned(s) => Cow::Owned(s.as_str().into()),
             }
         }
     }
@@ -656,207 +1348,102 @@ mod inner {
     //
     // My only guess is that the `clone()` calls we delegate to are just that much bigger than
     // `as_str()` that, when combined with a jump table, is blowing the icache, slowing things down.
-    impl<B: Clone> Clone for KStringInner<B> {
+    impl<B: Clone, const N: usize> Clone for KStringInner<B, N> {
         fn clone(&self) -> Self {
-            let tag = self.tag();
-            if tag.is_owned() {
-                unsafe {
-                    // SAFETY: `tag` ensures access to correct variant
-                    Self {
-                        owned: core::mem::ManuallyDrop::new(OwnedVariant::new(
-                            self.owned.payload.clone(),
-                        )),
-                    }
-                }
-            } else {
-                unsafe {
-                    // SAFETY: `tag` ensures access to correct variant
-                    // SAFETY: non-owned types are copyable
-                    core::mem::transmute_copy(self)
-                }
-            }
-        }
-    }
-
-    impl<B> Drop for KStringInner<B> {
-        fn drop(&mut self) {
-            let tag = self.tag();
-            if tag.is_owned() {
-                unsafe {
-                    // SAFETY: `tag` ensures we are using the right variant
-                    core::mem::ManuallyDrop::drop(&mut self.owned);
-                }
-            }
-        }
-    }
-
-    #[allow(unused)]
-    const LEN_SIZE: usize = size_of::<crate::stack::Len>();
-
-    #[allow(unused)]
-    const TAG_SIZE: usize = size_of::<Tag>();
-
-    #[allow(unused)]
-    const PAYLOAD_SIZE: usize = size_of::<crate::backend::DefaultStr>();
-    type Payload = Padding<PAYLOAD_SIZE>;
-
-    #[allow(unused)]
-    const TARGET_SIZE: usize = size_of::<Target>();
-    type Target = StdString;
-
-    #[allow(unused)]
-    const MAX_CAPACITY: usize = TARGET_SIZE - LEN_SIZE - TAG_SIZE;
-
-    // Performance seems to slow down when trying to occupy all of the padding left by `String`'s
-    // discriminant.  The question is whether faster len=1-16 "allocations" outweighs going to the heap
-    // for len=17-22.
-    #[allow(unused)]
-    const ALIGNED_CAPACITY: usize = PAYLOAD_SIZE - LEN_SIZE;
-
-    #[cfg(feature = "max_inline")]
-    const CAPACITY: usize = MAX_CAPACITY;
-    #[cfg(not(feature = "max_inline"))]
-    const CAPACITY: usize = ALIGNED_CAPACITY;
-
-    const PAYLOAD_PAD_SIZE: usize = TARGET_SIZE - PAYLOAD_SIZE - TAG_SIZE;
-    const INLINE_PAD_SIZE: usize = TARGET_SIZE - CAPACITY - LEN_SIZE - TAG_SIZE;
-
-    #[derive(Copy, Clone)]
-    #[repr(C)]
-    struct TagVariant {
-        payload: Payload,
-        pad: Padding<PAYLOAD_PAD_SIZE>,
-        tag: Tag,
-    }
-    static_assertions::assert_eq_size!(Target, TagVariant);
-
-    #[derive(Copy, Clone)]
-    #[repr(C)]
-    struct SingletonVariant {
-        payload: &'static str,
-        pad: Padding<PAYLOAD_PAD_SIZE>,
-        tag: Tag,
-    }
-    static_assertions::assert_eq_size!(Payload, &'static str);
-    static_assertions::assert_eq_size!(Target, SingletonVariant);
-
-    impl SingletonVariant {
-        #[inline]
-        const fn new(payload: &'static str) -> Self {
-            Self {
-                payload,
-                pad: Padding::new(),
-                tag: Tag::SINGLETON,
+            match self {
+                Self::Owned(s) => Self::Owned(s.clone()),
+                _ => unsafe {
+                    // SAFETY: non-owned variants are copyable
+                    core::ptr::read(self)
+                },
             }
         }
     }
 
-    impl core::fmt::Debug for SingletonVariant {
-        #[inline]
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            self.payload.fmt(f)
-        }
-    }
-
-    #[derive(Clone)]
-    #[repr(C)]
-    struct OwnedVariant<B> {
-        payload: B,
-        pad: Padding<PAYLOAD_PAD_SIZE>,
-        tag: Tag,
+    // With the `thin` layout, the length is packed next to the discriminant so a `&'static str`
+    // costs the same as a thin pointer.  This limits its length to what fits in
+    // `SINGLETON_LEN_SIZE` bytes, under 16 MiB on 32-bit targets.
+    //
+    // Otherwise, `B` takes two words anyway, leaving room for a whole `&'static str`.
+    #[cfg(feature = "thin")]
+    type SingletonLen = [u8; SINGLETON_LEN_SIZE];
+    #[cfg(feature = "thin")]
+    type SingletonPtr = StaticPtr;
+    #[cfg(not(feature = "thin"))]
+    type SingletonLen = ();
+    #[cfg(not(feature = "thin"))]
+    type SingletonPtr = &'static str;
+
+    #[cfg(feature = "thin")]
+    const SINGLETON_LEN_SIZE: usize = size_of::<usize>() - size_of::<u8>();
+
+    #[cfg(feature = "thin")]
+    #[inline]
+    const fn fits_singleton(s: &str) -> bool {
+        s.len() < 1 << (8 * SINGLETON_LEN_SIZE)
     }
-    static_assertions::assert_eq_size!(Payload, crate::backend::DefaultStr);
-    static_assertions::assert_eq_size!(Target, OwnedVariant<crate::backend::DefaultStr>);
 
-    impl<B> OwnedVariant<B> {
-        #[inline]
-        const fn new(payload: B) -> Self {
-            Self {
-                payload,
-                pad: Padding::new(),
-                tag: Tag::OWNED,
-            }
-        }
+    #[cfg(not(feature = "thin"))]
+    #[inline]
+    const fn fits_singleton(_s: &str) -> bool {
+        true
     }
 
-    impl<B: crate::backend::HeapStr> core::fmt::Debug for OwnedVariant<B> {
-        #[inline]
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            self.payload.fmt(f)
+    #[cfg(feature = "thin")]
+    #[inline]
+    const fn pack_singleton(s: &'static str) -> (SingletonLen, SingletonPtr) {
+        assert!(fits_singleton(s), "`&'static str` too large for `KString`");
+        let bytes = s.len().to_le_bytes();
+        let mut len = [0; SINGLETON_LEN_SIZE];
+        let mut i = 0;
+        while i < SINGLETON_LEN_SIZE {
+            len[i] = bytes[i];
+            i += 1;
         }
+        (len, StaticPtr(s.as_ptr()))
     }
 
-    #[derive(Copy, Clone)]
-    #[repr(C)]
-    struct InlineVariant {
-        payload: StackString<CAPACITY>,
-        pad: Padding<INLINE_PAD_SIZE>,
-        tag: Tag,
-    }
-    static_assertions::assert_eq_size!(Target, InlineVariant);
-
-    impl InlineVariant {
-        #[inline]
-        const fn new(payload: StackString<CAPACITY>) -> Self {
-            Self {
-                payload,
-                pad: Padding::new(),
-                tag: Tag::INLINE,
-            }
-        }
+    #[cfg(not(feature = "thin"))]
+    #[inline]
+    const fn pack_singleton(s: &'static str) -> (SingletonLen, SingletonPtr) {
+        ((), s)
     }
 
-    impl core::fmt::Debug for InlineVariant {
-        #[inline]
-        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-            self.payload.fmt(f)
+    #[cfg(feature = "thin")]
+    #[inline]
+    fn singleton(len: &SingletonLen, ptr: &SingletonPtr) -> &'static str {
+        let mut bytes = [0; size_of::<usize>()];
+        bytes[..SINGLETON_LEN_SIZE].copy_from_slice(len);
+        let len = usize::from_le_bytes(bytes);
+        unsafe {
+            // SAFETY: `ptr` and `len` were taken from a `&'static str` in `pack_singleton`
+            let bytes = core::slice::from_raw_parts(ptr.0, len);
+            core::str::from_utf8_unchecked(bytes)
         }
     }
 
-    #[derive(Copy, Clone, PartialEq, Eq)]
-    #[repr(transparent)]
-    struct Tag(u8);
-
-    impl Tag {
-        const SINGLETON: Self = Self(0);
-        const OWNED: Self = Self(u8::MAX);
-        const INLINE: Self = Self(1);
-
-        #[inline]
-        const fn is_singleton(self) -> bool {
-            self.0 == Self::SINGLETON.0
-        }
-
-        #[inline]
-        const fn is_owned(self) -> bool {
-            self.0 == Self::OWNED.0
-        }
-
-        #[inline]
-        const fn is_inline(self) -> bool {
-            !self.is_singleton() && !self.is_owned()
-        }
+    #[cfg(not(feature = "thin"))]
+    #[inline]
+    fn singleton(_len: &SingletonLen, ptr: &SingletonPtr) -> &'static str {
+        ptr
     }
 
+    #[cfg(feature = "thin")]
     #[derive(Copy, Clone)]
     #[repr(transparent)]
-    struct Padding<const L: usize>([core::mem::MaybeUninit<u8>; L]);
-
-    impl<const L: usize> Padding<L> {
-        const fn new() -> Self {
-            let padding = unsafe {
-                // SAFETY: Padding, never actually used
-                core::mem::MaybeUninit::uninit().assume_init()
-            };
-            Self(padding)
-        }
-    }
-
-    impl<const L: usize> Default for Padding<L> {
-        fn default() -> Self {
-            Self::new()
-        }
-    }
+    pub(super) struct StaticPtr(*const u8);
+
+    // SAFETY: Only ever points to the contents of an immutable `&'static str`
+    #[cfg(feature = "thin")]
+    unsafe impl Send for StaticPtr {}
+    // SAFETY: Only ever points to the contents of an immutable `&'static str`
+    #[cfg(feature = "thin")]
+    unsafe impl Sync for StaticPtr {}
+
+    #[cfg(feature = "thin")]
+    static_assertions::assert_eq_size!(KStringInner<(), 0>, [usize; 2]);
+    #[cfg(not(feature = "thin"))]
+    static_assertions::assert_eq_size!(KStringInner<(), 0>, [usize; 3]);
 }
 
 #[cfg(test)]
@@ -7,6 +7,7 @@ use alloc::{borrow::Cow, fmt};
 use crate::KStringBase;
 use crate::KStringRef;
 use crate::KStringRefInner;
+use crate::StorageKind;
 
 type StdString = String;
 type BoxedStr = Box<str>;
@@ -17,17 +18,36 @@ pub type KStringCow<'s> = KStringCowBase<'s, crate::backend::DefaultStr>;
 /// A reference to a UTF-8 encoded, immutable string.
 #[derive(Clone)]
 #[repr(transparent)]
-pub struct KStringCowBase<'s, B = crate::backend::DefaultStr> {
-    pub(crate) inner: KStringCowInner<'s, B>,
+pub struct KStringCowBase<
+    's,
+    B = crate::backend::DefaultStr,
+    const N: usize = { crate::string::CAPACITY },
+> {
+    pub(crate) inner: KStringCowInner<'s, B, N>,
 }
 
 #[derive(Clone)]
-pub(crate) enum KStringCowInner<'s, B> {
+pub(crate) enum KStringCowInner<'s, B, const N: usize> {
     Borrowed(&'s str),
-    Owned(KStringBase<B>),
-}
-
-impl<B> KStringCowBase<'_, B> {
+    Owned(KStringBase<B, N>),
+}
+
+static_assertions::assert_eq_size!(Option<KStringCow<'static>>, KStringCow<'static>);
+static_assertions::assert_eq_size!(
+    Option<KStringCowBase<'static, crate::backend::BoxedStr, { crate::string::ALIGNED_CAPACITY }>>,
+    KStringCowBase<'static, crate::backend::BoxedStr, { crate::string::ALIGNED_CAPACITY }>
+);
+static_assertions::assert_eq_size!(
+    Option<KStringCowBase<'static, crate::backend::BoxedStr, { crate::string::MAX_CAPACITY }>>,
+    KStringCowBase<'static, crate::backend::BoxedStr, { crate::string::MAX_CAPACITY }>
+);
+#[cfg(feature = "unsafe")]
+static_assertions::assert_eq_size!(
+    Option<KStringCowBase<'static, crate::backend::ThinBoxedStr, { crate::string::THIN_CAPACITY }>>,
+    KStringCowBase<'static, crate::backend::ThinBoxedStr, { crate::string::THIN_CAPACITY }>
+);
+
+impl<B, const N: usize> KStringCowBase<'_, B, N> {
     /// Create a new empty `KStringCowBase`.
     #[inline]
     #[must_use]
@@ -45,7 +65,7 @@ impl<B> KStringCowBase<'_, B> {
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> KStringCowBase<'s, B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> KStringCowBase<'s, B, N> {
     /// Create an owned `KStringCowBase`.
     #[inline]
     #[must_use]
@@ -83,10 +103,40 @@ impl<'s, B: crate::backend::HeapStr> KStringCowBase<'s, B> {
     /// Clone the data into an owned-type.
     #[inline]
     #[must_use]
-    pub fn into_owned(self) -> KStringBase<B> {
+    pub fn into_owned(self) -> KStringBase<B, N> {
         self.inner.into_owned()
     }
 
+    /// Where the string's data is stored
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kstring::{KStringCow, StorageKind};
+    ///
+    /// let borrowed = String::from("key");
+    /// assert_eq!(KStringCow::from_ref(&borrowed).storage(), StorageKind::Borrowed);
+    /// assert_eq!(KStringCow::from_string(borrowed).storage(), StorageKind::Inline);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn storage(&self) -> StorageKind {
+        match &self.inner {
+            KStringCowInner::Borrowed(_) => StorageKind::Borrowed,
+            KStringCowInner::Owned(s) => s.storage(),
+        }
+    }
+
+    /// Bytes of string data owned on the heap, see [`KStringBase::heap_bytes`]
+    #[inline]
+    #[must_use]
+    pub fn heap_bytes(&self) -> usize {
+        match &self.inner {
+            KStringCowInner::Borrowed(_) => 0,
+            KStringCowInner::Owned(s) => s.heap_bytes(),
+        }
+    }
+
     /// Extracts a string slice containing the entire `KStringCowBase`.
     #[inline]
     #[must_use]
@@ -94,6 +144,27 @@ impl<'s, B: crate::backend::HeapStr> KStringCowBase<'s, B> {
         self.inner.as_str()
     }
 
+    /// Whether both point at the same string data, like [`Arc::ptr_eq`][alloc::sync::Arc::ptr_eq]
+    ///
+    /// This holds for clones of a borrowed or `'static` string and of a shared heap string (e.g.
+    /// with the `arc` feature).  An inline string only points at its own data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kstring::KStringCow;
+    ///
+    /// let owner = String::from("key");
+    /// let key = KStringCow::from_ref(&owner);
+    /// assert!(KStringCow::ptr_eq(&key, &key.clone()));
+    /// assert!(!KStringCow::ptr_eq(&key, &KStringCow::from_static("key")));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        core::ptr::eq(this.as_str(), other.as_str())
+    }
+
     /// Convert to a mutable string type, cloning the data if necessary.
     #[inline]
     #[must_use]
@@ -116,7 +187,7 @@ impl<'s, B: crate::backend::HeapStr> KStringCowBase<'s, B> {
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> KStringCowInner<'s, B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> KStringCowInner<'s, B, N> {
     #[inline]
     fn as_ref(&self) -> KStringRef<'_> {
         match self {
@@ -126,7 +197,7 @@ impl<'s, B: crate::backend::HeapStr> KStringCowInner<'s, B> {
     }
 
     #[inline]
-    fn into_owned(self) -> KStringBase<B> {
+    fn into_owned(self) -> KStringBase<B, N> {
         match self {
             Self::Borrowed(s) => KStringBase::from_ref(s),
             Self::Owned(s) => s,
@@ -159,7 +230,7 @@ impl<'s, B: crate::backend::HeapStr> KStringCowInner<'s, B> {
     }
 }
 
-impl<B: crate::backend::HeapStr> core::ops::Deref for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> core::ops::Deref for KStringCowBase<'_, B, N> {
     type Target = str;
 
     #[inline]
@@ -168,79 +239,59 @@ impl<B: crate::backend::HeapStr> core::ops::Deref for KStringCowBase<'_, B> {
     }
 }
 
-impl<B: crate::backend::HeapStr> Eq for KStringCowBase<'_, B> {}
+impl<B: crate::backend::HeapStr, const N: usize> Eq for KStringCowBase<'_, B, N> {}
 
-impl<'s, B: crate::backend::HeapStr> PartialEq<Self> for KStringCowBase<'s, B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> PartialEq<Self> for KStringCowBase<'s, B, N> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        PartialEq::eq(self.as_str(), other.as_str())
-    }
-}
-
-impl<B: crate::backend::HeapStr> PartialEq<str> for KStringCowBase<'_, B> {
-    #[inline]
-    fn eq(&self, other: &str) -> bool {
-        PartialEq::eq(self.as_str(), other)
-    }
-}
-
-impl<'s, B: crate::backend::HeapStr> PartialEq<&'s str> for KStringCowBase<'s, B> {
-    #[inline]
-    fn eq(&self, other: &&str) -> bool {
-        PartialEq::eq(self.as_str(), *other)
-    }
-}
-
-impl<B: crate::backend::HeapStr> PartialEq<String> for KStringCowBase<'_, B> {
-    #[inline]
-    fn eq(&self, other: &StdString) -> bool {
-        PartialEq::eq(self.as_str(), other.as_str())
+        // Skip comparing the bytes of shared strings, like clones of an `Arc`
+        Self::ptr_eq(self, other) || PartialEq::eq(self.as_str(), other.as_str())
     }
 }
 
-impl<B: crate::backend::HeapStr> Ord for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> Ord for KStringCowBase<'_, B, N> {
     #[inline]
     fn cmp(&self, other: &Self) -> core::cmp::Ordering {
         self.as_str().cmp(other.as_str())
     }
 }
 
-impl<B: crate::backend::HeapStr> PartialOrd for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> PartialOrd for KStringCowBase<'_, B, N> {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<B: crate::backend::HeapStr> core::hash::Hash for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> core::hash::Hash for KStringCowBase<'_, B, N> {
     #[inline]
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
         self.as_str().hash(state);
     }
 }
 
-impl<B: crate::backend::HeapStr> fmt::Debug for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> fmt::Debug for KStringCowBase<'_, B, N> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.as_str().fmt(f)
     }
 }
 
-impl<B: crate::backend::HeapStr> fmt::Display for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> fmt::Display for KStringCowBase<'_, B, N> {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Display::fmt(self.as_str(), f)
     }
 }
 
-impl<B: crate::backend::HeapStr> AsRef<str> for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> AsRef<str> for KStringCowBase<'_, B, N> {
     #[inline]
     fn as_ref(&self) -> &str {
         self.as_str()
     }
 }
 
-impl<B: crate::backend::HeapStr> AsRef<[u8]> for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> AsRef<[u8]> for KStringCowBase<'_, B, N> {
     #[inline]
     fn as_ref(&self) -> &[u8] {
         self.as_bytes()
@@ -248,7 +299,9 @@ impl<B: crate::backend::HeapStr> AsRef<[u8]> for KStringCowBase<'_, B> {
 }
 
 #[cfg(feature = "std")]
-impl<B: crate::backend::HeapStr> AsRef<std::ffi::OsStr> for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> AsRef<std::ffi::OsStr>
+    for KStringCowBase<'_, B, N>
+{
     #[inline]
     fn as_ref(&self) -> &std::ffi::OsStr {
         (**self).as_ref()
@@ -256,44 +309,54 @@ impl<B: crate::backend::HeapStr> AsRef<std::ffi::OsStr> for KStringCowBase<'_, B
 }
 
 #[cfg(feature = "std")]
-impl<B: crate::backend::HeapStr> AsRef<std::path::Path> for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> AsRef<std::path::Path>
+    for KStringCowBase<'_, B, N>
+{
     #[inline]
     fn as_ref(&self) -> &std::path::Path {
         std::path::Path::new(self)
     }
 }
 
-impl<B: crate::backend::HeapStr> core::borrow::Borrow<str> for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> core::borrow::Borrow<str>
+    for KStringCowBase<'_, B, N>
+{
     #[inline]
     fn borrow(&self) -> &str {
         self.as_str()
     }
 }
 
-impl<B> Default for KStringCowBase<'_, B> {
+impl<B, const N: usize> Default for KStringCowBase<'_, B, N> {
     #[inline]
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<B: crate::backend::HeapStr> From<KStringBase<B>> for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> From<KStringBase<B, N>>
+    for KStringCowBase<'_, B, N>
+{
     #[inline]
-    fn from(other: KStringBase<B>) -> Self {
+    fn from(other: KStringBase<B, N>) -> Self {
         let inner = KStringCowInner::Owned(other);
         Self { inner }
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<&'s KStringBase<B>> for KStringCowBase<'s, B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<&'s KStringBase<B, N>>
+    for KStringCowBase<'s, B, N>
+{
     #[inline]
-    fn from(other: &'s KStringBase<B>) -> Self {
+    fn from(other: &'s KStringBase<B, N>) -> Self {
         let other = other.as_ref();
         other.into()
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<KStringRef<'s>> for KStringCowBase<'s, B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<KStringRef<'s>>
+    for KStringCowBase<'s, B, N>
+{
     #[inline]
     fn from(other: KStringRef<'s>) -> Self {
         match other.inner {
@@ -303,7 +366,9 @@ impl<'s, B: crate::backend::HeapStr> From<KStringRef<'s>> for KStringCowBase<'s,
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<&'s KStringRef<'s>> for KStringCowBase<'s, B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<&'s KStringRef<'s>>
+    for KStringCowBase<'s, B, N>
+{
     #[inline]
     fn from(other: &'s KStringRef<'s>) -> Self {
         match other.inner {
@@ -313,21 +378,23 @@ impl<'s, B: crate::backend::HeapStr> From<&'s KStringRef<'s>> for KStringCowBase
     }
 }
 
-impl<B: crate::backend::HeapStr> From<StdString> for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> From<StdString> for KStringCowBase<'_, B, N> {
     #[inline]
     fn from(other: StdString) -> Self {
         Self::from_string(other)
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<&'s StdString> for KStringCowBase<'s, B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<&'s StdString>
+    for KStringCowBase<'s, B, N>
+{
     #[inline]
     fn from(other: &'s StdString) -> Self {
         Self::from_ref(other.as_str())
     }
 }
 
-impl<B: crate::backend::HeapStr> From<BoxedStr> for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> From<BoxedStr> for KStringCowBase<'_, B, N> {
     #[inline]
     fn from(other: BoxedStr) -> Self {
         // Since the memory is already allocated, don't bother moving it into a FixedString
@@ -335,21 +402,23 @@ impl<B: crate::backend::HeapStr> From<BoxedStr> for KStringCowBase<'_, B> {
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<&'s BoxedStr> for KStringCowBase<'s, B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<&'s BoxedStr>
+    for KStringCowBase<'s, B, N>
+{
     #[inline]
     fn from(other: &'s BoxedStr) -> Self {
         Self::from_ref(other)
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<&'s str> for KStringCowBase<'s, B> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<&'s str> for KStringCowBase<'s, B, N> {
     #[inline]
     fn from(other: &'s str) -> Self {
         Self::from_ref(other)
     }
 }
 
-impl<B: crate::backend::HeapStr> core::str::FromStr for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> core::str::FromStr for KStringCowBase<'_, B, N> {
     type Err = core::convert::Infallible;
     #[inline]
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -358,7 +427,7 @@ impl<B: crate::backend::HeapStr> core::str::FromStr for KStringCowBase<'_, B> {
 }
 
 #[cfg(feature = "serde")]
-impl<B: crate::backend::HeapStr> serde::Serialize for KStringCowBase<'_, B> {
+impl<B: crate::backend::HeapStr, const N: usize> serde::Serialize for KStringCowBase<'_, B, N> {
     #[inline]
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -368,8 +437,12 @@ impl<B: crate::backend::HeapStr> serde::Serialize for KStringCowBase<'_, B> {
     }
 }
 
+/// Always produces an owned string, so this works with `DeserializeOwned`.  See
+/// [`kstring::serde::borrow`][crate::serde::borrow] to borrow from the input instead.
 #[cfg(feature = "serde")]
-impl<'de, B: crate::backend::HeapStr> serde::Deserialize<'de> for KStringCowBase<'_, B> {
+impl<'de, B: crate::backend::HeapStr, const N: usize> serde::Deserialize<'de>
+    for KStringCowBase<'_, B, N>
+{
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
@@ -378,6 +451,209 @@ impl<'de, B: crate::backend::HeapStr> serde::Deserialize<'de> for KStringCowBase
     }
 }
 
+/// Archived as an [`ArchivedString`][rkyv::string::ArchivedString], like [`KStringBase`].
+#[cfg(feature = "rkyv")]
+impl<B: crate::backend::HeapStr, const N: usize> rkyv::Archive for KStringCowBase<'_, B, N> {
+    type Archived = rkyv::string::ArchivedString;
+    type Resolver = rkyv::string::StringResolver;
+
+    #[inline]
+    fn resolve(&self, resolver: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+        rkyv::string::ArchivedString::resolve_from_str(self.as_str(), resolver, out);
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<B: crate::backend::HeapStr, const N: usize, S> rkyv::Serialize<S> for KStringCowBase<'_, B, N>
+where
+    S: rkyv::rancor::Fallible + rkyv::ser::Allocator + rkyv::ser::Writer + ?Sized,
+    S::Error: rkyv::rancor::Source,
+{
+    #[inline]
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        rkyv::string::ArchivedString::serialize_from_str(self.as_str(), serializer)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<B: crate::backend::HeapStr, const N: usize, D> rkyv::Deserialize<KStringCowBase<'_, B, N>, D>
+    for rkyv::string::ArchivedString
+where
+    D: rkyv::rancor::Fallible + ?Sized,
+{
+    #[inline]
+    fn deserialize(
+        &self,
+        _deserializer: &mut D,
+    ) -> Result<KStringCowBase<'static, B, N>, D::Error> {
+        Ok(KStringBase::from_ref(self.as_str()).into())
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<B: crate::backend::HeapStr, const N: usize> PartialEq<KStringCowBase<'_, B, N>>
+    for rkyv::string::ArchivedString
+{
+    #[inline]
+    fn eq(&self, other: &KStringCowBase<'_, B, N>) -> bool {
+        PartialEq::eq(self.as_str(), other.as_str())
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<B: crate::backend::HeapStr, const N: usize> PartialEq<rkyv::string::ArchivedString>
+    for KStringCowBase<'_, B, N>
+{
+    #[inline]
+    fn eq(&self, other: &rkyv::string::ArchivedString) -> bool {
+        PartialEq::eq(self.as_str(), other.as_str())
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<B: crate::backend::HeapStr, const N: usize> borsh::BorshSerialize
+    for KStringCowBase<'_, B, N>
+{
+    #[inline]
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        self.as_str().serialize(writer)
+    }
+}
+
+/// Always owned, as `borsh` reads from a stream; see [`KStringBase`].
+#[cfg(feature = "borsh")]
+impl<B: crate::backend::HeapStr, const N: usize> borsh::BorshDeserialize
+    for KStringCowBase<'_, B, N>
+{
+    #[inline]
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        KStringBase::deserialize_reader(reader).map(Self::from)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<B: crate::backend::HeapStr, const N: usize> bincode::Encode for KStringCowBase<'_, B, N> {
+    #[inline]
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        self.as_str().encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<Context, B: crate::backend::HeapStr, const N: usize> bincode::Decode<Context>
+    for KStringCowBase<'_, B, N>
+{
+    #[inline]
+    fn decode<D: bincode::de::Decoder<Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        KStringBase::decode(decoder).map(Self::from)
+    }
+}
+
+/// Borrows the string from the input rather than copying it.
+#[cfg(feature = "bincode")]
+impl<'de: 's, 's, Context, B: crate::backend::HeapStr, const N: usize>
+    bincode::BorrowDecode<'de, Context> for KStringCowBase<'s, B, N>
+{
+    #[inline]
+    fn borrow_decode<D: bincode::de::BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        <&'de str>::borrow_decode(decoder).map(Self::from_ref)
+    }
+}
+
+/// Covers data borrowed from the input along with everything [`KStringBase`] covers.
+#[cfg(feature = "arbitrary")]
+impl<'a, B: crate::backend::HeapStr, const N: usize> arbitrary::Arbitrary<'a>
+    for KStringCowBase<'a, B, N>
+{
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if u.ratio(1, 4)? {
+            u.arbitrary().map(Self::from_ref)
+        } else {
+            u.arbitrary::<KStringBase<B, N>>().map(Self::from)
+        }
+    }
+}
+
+/// Covers borrowed data along with everything [`KStringBase`] covers.
+#[cfg(feature = "quickcheck")]
+impl<B: crate::backend::HeapStr + 'static, const N: usize> quickcheck::Arbitrary
+    for KStringCowBase<'static, B, N>
+{
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        if <u8 as quickcheck::Arbitrary>::arbitrary(g) % 4 == 0 {
+            let s = g
+                .choose(crate::generate::STATICS)
+                .expect("`STATICS` is non-empty");
+            Self::from_ref(s)
+        } else {
+            <KStringBase<B, N> as quickcheck::Arbitrary>::arbitrary(g).into()
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let s = String::from(self.as_str());
+        Box::new(quickcheck::Arbitrary::shrink(&s).map(Self::from_string))
+    }
+}
+
+/// See [`strategy::kstring_cow`][crate::strategy::kstring_cow].
+#[cfg(feature = "proptest")]
+impl<B: crate::backend::HeapStr + 'static, const N: usize> proptest::arbitrary::Arbitrary
+    for KStringCowBase<'static, B, N>
+{
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::boxed(crate::strategy::kstring_cow())
+    }
+}
+
+#[cfg(feature = "get-size")]
+impl<B: crate::backend::HeapStr, const N: usize> get_size::GetSize for KStringCowBase<'_, B, N> {
+    #[inline]
+    fn get_heap_size(&self) -> usize {
+        match &self.inner {
+            KStringCowInner::Borrowed(_) => 0,
+            KStringCowInner::Owned(s) => s.get_heap_size(),
+        }
+    }
+}
+
+#[cfg(feature = "datasize")]
+impl<B: crate::backend::HeapStr, const N: usize> datasize::DataSize for KStringCowBase<'_, B, N> {
+    const IS_DYNAMIC: bool = true;
+    const STATIC_HEAP_SIZE: usize = 0;
+
+    #[inline]
+    fn estimate_heap_size(&self) -> usize {
+        match &self.inner {
+            KStringCowInner::Borrowed(_) => 0,
+            KStringCowInner::Owned(s) => s.estimate_heap_size(),
+        }
+    }
+}
+
+#[cfg(feature = "deepsize")]
+impl<B: crate::backend::HeapStr + deepsize::DeepSizeOf, const N: usize> deepsize::DeepSizeOf
+    for KStringCowBase<'_, B, N>
+{
+    #[inline]
+    fn deep_size_of_children(&self, context: &mut deepsize::Context) -> usize {
+        match &self.inner {
+            KStringCowInner::Borrowed(_) => 0,
+            KStringCowInner::Owned(s) => s.deep_size_of_children(context),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
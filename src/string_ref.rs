@@ -8,6 +8,7 @@ use core::fmt;
 
 use crate::KStringBase;
 use crate::KStringCowBase;
+use crate::StorageKind;
 
 type StdString = String;
 type BoxedStr = Box<str>;
@@ -25,6 +26,8 @@ pub(crate) enum KStringRefInner<'s> {
     Singleton(&'static str),
 }
 
+static_assertions::assert_eq_size!(Option<KStringRef<'static>>, KStringRef<'static>);
+
 impl<'s> KStringRef<'s> {
     /// Create a new empty `KStringBase`.
     #[inline]
@@ -59,6 +62,28 @@ impl<'s> KStringRef<'s> {
         self.inner.to_owned()
     }
 
+    /// Where the string's data is stored
+    ///
+    /// This is either [`StorageKind::Static`] or [`StorageKind::Borrowed`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kstring::{KStringRef, StorageKind};
+    ///
+    /// assert_eq!(KStringRef::from_static("key").storage(), StorageKind::Static);
+    /// let borrowed = String::from("key");
+    /// assert_eq!(KStringRef::from_ref(&borrowed).storage(), StorageKind::Borrowed);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn storage(&self) -> StorageKind {
+        match self.inner {
+            KStringRefInner::Borrowed(_) => StorageKind::Borrowed,
+            KStringRefInner::Singleton(_) => StorageKind::Static,
+        }
+    }
+
     /// Extracts a string slice containing the entire `KStringRef`.
     #[inline]
     #[must_use]
@@ -66,6 +91,24 @@ impl<'s> KStringRef<'s> {
         self.inner.as_str()
     }
 
+    /// Whether both point at the same string data, like [`Arc::ptr_eq`][alloc::sync::Arc::ptr_eq]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kstring::KStringRef;
+    ///
+    /// let owner = String::from("key");
+    /// let key = KStringRef::from_ref(&owner);
+    /// assert!(KStringRef::ptr_eq(&key, &KStringRef::from(&owner)));
+    /// assert!(!KStringRef::ptr_eq(&key, &KStringRef::from_static("key")));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        core::ptr::eq(this.as_str(), other.as_str())
+    }
+
     /// Convert to a mutable string type, cloning the data if necessary.
     #[inline]
     #[must_use]
@@ -77,7 +120,7 @@ impl<'s> KStringRef<'s> {
 impl KStringRefInner<'_> {
     #[inline]
     #[allow(clippy::wrong_self_convention)]
-    fn to_owned<B: crate::backend::HeapStr>(&self) -> KStringBase<B> {
+    pub(crate) fn to_owned<B: crate::backend::HeapStr, const N: usize>(&self) -> KStringBase<B, N> {
         match self {
             Self::Borrowed(s) => KStringBase::from_ref(s),
             Self::Singleton(s) => KStringBase::from_static(s),
@@ -112,28 +155,8 @@ impl Eq for KStringRef<'_> {}
 impl<'s> PartialEq<Self> for KStringRef<'s> {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        PartialEq::eq(self.as_str(), other.as_str())
-    }
-}
-
-impl PartialEq<str> for KStringRef<'_> {
-    #[inline]
-    fn eq(&self, other: &str) -> bool {
-        PartialEq::eq(self.as_str(), other)
-    }
-}
-
-impl<'s> PartialEq<&'s str> for KStringRef<'s> {
-    #[inline]
-    fn eq(&self, other: &&str) -> bool {
-        PartialEq::eq(self.as_str(), *other)
-    }
-}
-
-impl PartialEq<String> for KStringRef<'_> {
-    #[inline]
-    fn eq(&self, other: &StdString) -> bool {
-        PartialEq::eq(self.as_str(), other.as_str())
+        // Skip comparing the bytes of shared strings, like clones of an `Arc`
+        Self::ptr_eq(self, other) || PartialEq::eq(self.as_str(), other.as_str())
     }
 }
 
@@ -216,16 +239,20 @@ impl Default for KStringRef<'_> {
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<&'s KStringBase<B>> for KStringRef<'s> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<&'s KStringBase<B, N>>
+    for KStringRef<'s>
+{
     #[inline]
-    fn from(other: &'s KStringBase<B>) -> Self {
+    fn from(other: &'s KStringBase<B, N>) -> Self {
         other.as_ref()
     }
 }
 
-impl<'s, B: crate::backend::HeapStr> From<&'s KStringCowBase<'s, B>> for KStringRef<'s> {
+impl<'s, B: crate::backend::HeapStr, const N: usize> From<&'s KStringCowBase<'s, B, N>>
+    for KStringRef<'s>
+{
     #[inline]
-    fn from(other: &'s KStringCowBase<'s, B>) -> Self {
+    fn from(other: &'s KStringCowBase<'s, B, N>) -> Self {
         other.as_ref()
     }
 }
@@ -274,6 +301,75 @@ impl<'de: 's, 's> serde::Deserialize<'de> for KStringRef<'s> {
     }
 }
 
+#[cfg(feature = "get-size")]
+impl get_size::GetSize for KStringRef<'_> {}
+
+#[cfg(feature = "datasize")]
+impl datasize::DataSize for KStringRef<'_> {
+    const IS_DYNAMIC: bool = false;
+    const STATIC_HEAP_SIZE: usize = 0;
+
+    #[inline]
+    fn estimate_heap_size(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(feature = "deepsize")]
+impl deepsize::DeepSizeOf for KStringRef<'_> {
+    #[inline]
+    fn deep_size_of_children(&self, _context: &mut deepsize::Context) -> usize {
+        0
+    }
+}
+
+/// Covers `'static` data and data borrowed from the input.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for KStringRef<'a> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if u.arbitrary()? {
+            u.choose(crate::generate::STATICS)
+                .map(|s| Self::from_static(s))
+        } else {
+            u.arbitrary().map(Self::from_ref)
+        }
+    }
+}
+
+/// Covers `'static` and borrowed data.
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for KStringRef<'static> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let s = g
+            .choose(crate::generate::STATICS)
+            .expect("`STATICS` is non-empty");
+        if <bool as quickcheck::Arbitrary>::arbitrary(g) {
+            Self::from_static(s)
+        } else {
+            Self::from_ref(s)
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        if self.is_empty() {
+            quickcheck::empty_shrinker()
+        } else {
+            quickcheck::single_shrinker(Self::new())
+        }
+    }
+}
+
+/// See [`strategy::kstring_ref`][crate::strategy::kstring_ref].
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for KStringRef<'static> {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        proptest::strategy::Strategy::boxed(crate::strategy::kstring_ref())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,123 @@
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use kstring::backend::BoxedStr;
+use kstring::backend::HeapStr;
+use kstring::KStringBase;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Custom backend that counts how often the heap is used
+#[derive(Clone, Debug)]
+struct CountedStr(std::sync::Arc<str>);
+
+impl HeapStr for CountedStr {
+    fn from_str(other: &str) -> Self {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        Self(other.into())
+    }
+
+    fn from_string(other: String) -> Self {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        Self(other.into())
+    }
+
+    fn from_boxed_str(other: BoxedStr) -> Self {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        Self(other.into())
+    }
+
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+type CountedKString = KStringBase<CountedStr>;
+
+#[test]
+fn test_custom_backend() {
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+
+    let inline = CountedKString::from_ref("short");
+    let singleton = CountedKString::from_static("a static string that is long enough for the heap");
+    assert_eq!(ALLOCATIONS.load(Ordering::SeqCst), before);
+
+    let owned = CountedKString::from_ref("a borrowed string that is long enough for the heap");
+    let boxed = CountedKString::from_boxed("boxed".into());
+    let string = CountedKString::from_string(String::from(
+        "an owned string that is long enough for the heap",
+    ));
+    // Already-allocated strings are handed to the backend as-is
+    assert_eq!(ALLOCATIONS.load(Ordering::SeqCst), before + 3);
+
+    #[allow(clippy::redundant_clone)]
+    let cloned = owned.clone();
+    assert_eq!(ALLOCATIONS.load(Ordering::SeqCst), before + 3);
+
+    assert_eq!(inline, "short");
+    assert_eq!(
+        singleton,
+        "a static string that is long enough for the heap"
+    );
+    assert_eq!(owned, "a borrowed string that is long enough for the heap");
+    assert_eq!(cloned, owned);
+    assert_eq!(boxed, "boxed");
+    assert_eq!(string, "an owned string that is long enough for the heap");
+}
+
+#[test]
+fn test_from_heap() {
+    let shared = CountedStr(std::sync::Arc::from("shared storage between keys"));
+    let first = CountedKString::from_heap(shared.clone());
+    let second = CountedKString::from_heap(shared);
+    assert_eq!(first.as_ptr(), second.as_ptr());
+}
+
+#[test]
+#[cfg(feature = "unsafe")]
+fn test_thin_arc() {
+    use kstring::backend::ThinArcStr;
+
+    let empty = ThinArcStr::from_str("");
+    assert_eq!(empty.as_str(), "");
+
+    let long = "a string that is long enough for the heap";
+    let uut = KStringBase::<ThinArcStr>::from_ref(long);
+    let shared = uut.clone();
+    assert_eq!(uut.as_ptr(), shared.as_ptr());
+    drop(uut);
+    let handle = std::thread::spawn(move || shared.to_string());
+    assert_eq!(handle.join().unwrap(), long);
+
+    let owned = KStringBase::<ThinArcStr>::from_string(String::from(long));
+    assert_eq!(owned, long);
+    assert_eq!(KStringBase::<ThinArcStr>::from_static(long), long);
+}
+
+#[test]
+#[cfg(feature = "unsafe")]
+fn test_thin_boxed() {
+    use kstring::backend::ThinBoxedStr;
+
+    let empty = ThinBoxedStr::from_str("");
+    assert_eq!(empty.as_str(), "");
+
+    let long = "a string that is long enough for the heap";
+    let uut = KStringBase::<ThinBoxedStr>::from_ref(long);
+    let copy = uut.clone();
+    assert_ne!(uut.as_ptr(), copy.as_ptr());
+    drop(uut);
+    let handle = std::thread::spawn(move || copy.to_string());
+    assert_eq!(handle.join().unwrap(), long);
+
+    let boxed = KStringBase::<ThinBoxedStr>::from_boxed(long.into());
+    assert_eq!(boxed, long);
+}
+
+#[test]
+#[cfg(feature = "thin")]
+fn test_thin_layout() {
+    assert_eq!(size_of::<kstring::KString>(), 2 * size_of::<usize>());
+    let longest = "0".repeat(kstring::KString::INLINE_CAPACITY);
+    assert!(kstring::KString::try_inline(&longest).is_some());
+}
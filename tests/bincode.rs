@@ -0,0 +1,88 @@
+#![cfg(feature = "bincode")]
+
+use kstring::KString;
+use kstring::KStringCow;
+use kstring::StackString;
+use kstring::StorageKind;
+
+const LONG: &str = "a-long-key-that-would-not-fit-inline";
+
+fn encode<T: bincode::Encode + ?Sized>(value: &T) -> Vec<u8> {
+    bincode::encode_to_vec(value, bincode::config::standard()).unwrap()
+}
+
+fn decode<T: bincode::Decode<()>>(bytes: &[u8]) -> Result<T, bincode::error::DecodeError> {
+    bincode::decode_from_slice(bytes, bincode::config::standard()).map(|(value, _)| value)
+}
+
+fn borrow_decode<'de, T: bincode::BorrowDecode<'de, ()>>(
+    bytes: &'de [u8],
+) -> Result<T, bincode::error::DecodeError> {
+    bincode::borrow_decode_from_slice(bytes, bincode::config::standard()).map(|(value, _)| value)
+}
+
+#[test]
+fn test_roundtrip() {
+    for s in ["", "key", LONG] {
+        let bytes = encode(&KString::from_ref(s));
+        assert_eq!(bytes, encode(s));
+
+        let kstring: KString = decode(&bytes).unwrap();
+        assert_eq!(kstring, s);
+        let kstring: KString = borrow_decode(&bytes).unwrap();
+        assert_eq!(kstring, s);
+        let cow: KStringCow<'_> = decode(&bytes).unwrap();
+        assert_eq!(cow, s);
+    }
+}
+
+#[test]
+fn test_decode_storage() {
+    let kstring: KString = decode(&encode("key")).unwrap();
+    assert_eq!(kstring.storage(), StorageKind::Inline);
+    let kstring: KString = decode(&encode(LONG)).unwrap();
+    assert_eq!(kstring.storage(), StorageKind::Heap);
+}
+
+#[test]
+fn test_cow_borrows() {
+    let bytes = encode(LONG);
+    let cow: KStringCow<'_> = borrow_decode(&bytes).unwrap();
+    assert_eq!(cow, LONG);
+    assert_eq!(cow.storage(), StorageKind::Borrowed);
+
+    let cow: KStringCow<'_> = decode(&bytes).unwrap();
+    assert_eq!(cow.storage(), StorageKind::Heap);
+}
+
+#[test]
+fn test_invalid() {
+    let bytes = encode(&[0xff_u8, 0xfe][..]);
+    assert!(matches!(
+        decode::<KString>(&bytes),
+        Err(bincode::error::DecodeError::Utf8 { .. })
+    ));
+    assert!(matches!(
+        decode::<KString>(&encode(&[0xff_u8; 64][..])),
+        Err(bincode::error::DecodeError::Utf8 { .. })
+    ));
+
+    let mut bytes = encode(LONG);
+    bytes.truncate(bytes.len() - 1);
+    assert!(decode::<KString>(&bytes).is_err());
+}
+
+#[test]
+fn test_stack_string() {
+    let stack = StackString::<8>::new("key");
+    let bytes = encode(&stack);
+    assert_eq!(bytes, encode("key"));
+    assert_eq!(decode::<StackString<8>>(&bytes).unwrap(), "key");
+    assert_eq!(borrow_decode::<StackString<8>>(&bytes).unwrap(), "key");
+
+    let err = decode::<StackString<8>>(&encode("123456789")).unwrap_err();
+    assert!(matches!(
+        err,
+        bincode::error::DecodeError::OtherString(msg) if msg == "string of 9 bytes exceeds capacity of 8"
+    ));
+}
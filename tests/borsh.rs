@@ -0,0 +1,56 @@
+#![cfg(feature = "borsh")]
+
+use kstring::KString;
+use kstring::KStringCow;
+use kstring::StackString;
+use kstring::StorageKind;
+
+const LONG: &str = "a-long-key-that-would-not-fit-inline";
+
+#[test]
+fn test_roundtrip() {
+    for s in ["", "key", LONG, &LONG.repeat(1000)] {
+        let bytes = borsh::to_vec(&KString::from_ref(s)).unwrap();
+        assert_eq!(bytes, borsh::to_vec(s).unwrap());
+
+        let kstring: KString = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(kstring, s);
+        let cow: KStringCow<'_> = borsh::from_slice(&bytes).unwrap();
+        assert_eq!(cow, s);
+    }
+}
+
+#[test]
+fn test_decode_storage() {
+    let kstring: KString = borsh::from_slice(&borsh::to_vec("key").unwrap()).unwrap();
+    assert_eq!(kstring.storage(), StorageKind::Inline);
+    let kstring: KString = borsh::from_slice(&borsh::to_vec(LONG).unwrap()).unwrap();
+    assert_eq!(kstring.storage(), StorageKind::Heap);
+}
+
+#[test]
+fn test_invalid() {
+    let bytes = borsh::to_vec(&[0xff_u8, 0xfe][..]).unwrap();
+    assert!(borsh::from_slice::<KString>(&bytes).is_err());
+
+    let mut bytes = borsh::to_vec(LONG).unwrap();
+    bytes.truncate(bytes.len() - 1);
+    assert!(borsh::from_slice::<KString>(&bytes).is_err());
+
+    // A corrupt length shouldn't allocate it all up front
+    let bytes = u32::MAX.to_le_bytes();
+    assert!(borsh::from_slice::<KString>(&bytes).is_err());
+}
+
+#[test]
+fn test_stack_string() {
+    let stack = StackString::<8>::new("key");
+    let bytes = borsh::to_vec(&stack).unwrap();
+    assert_eq!(bytes, borsh::to_vec("key").unwrap());
+    assert_eq!(borsh::from_slice::<StackString<8>>(&bytes).unwrap(), "key");
+
+    let bytes = borsh::to_vec("123456789").unwrap();
+    let err = borsh::from_slice::<StackString<8>>(&bytes).unwrap_err();
+    assert_eq!(err.kind(), borsh::io::ErrorKind::InvalidData);
+    assert_eq!(err.to_string(), "string of 9 bytes exceeds capacity of 8");
+}
@@ -0,0 +1,110 @@
+use std::fmt::Write as _;
+
+use kstring::backend::BoxedStr;
+use kstring::backend::HeapStr;
+use kstring::KStringBase;
+use kstring::KStringBuilder;
+
+type Builder = KStringBuilder<BoxedStr, 8>;
+
+#[test]
+fn test_stays_inline() {
+    let mut builder = Builder::new();
+    builder.push_str("abcd");
+    builder.push('e');
+    builder.extend(["f", "g"]);
+    builder.extend(['h']);
+    assert!(!builder.is_heap());
+    assert_eq!(builder.len(), 8);
+
+    let s = builder.finish();
+    assert_eq!(s, "abcdefgh");
+    assert!(KStringBase::<BoxedStr, 8>::try_inline(&s).is_some());
+}
+
+#[test]
+fn test_spills_to_heap() {
+    let mut builder = Builder::new();
+    builder.push_str("abcdefg");
+    assert!(!builder.is_heap());
+    write!(builder, "h{}", 1).unwrap();
+    assert!(builder.is_heap());
+    builder.push('ƒ');
+    assert_eq!(builder.as_str(), "abcdefgh1ƒ");
+
+    let s: KStringBase<BoxedStr, 8> = builder.into();
+    assert_eq!(s, "abcdefgh1ƒ");
+}
+
+#[test]
+fn test_partial_char_overflow() {
+    // A multi-byte `char` that doesn't fully fit must spill whole
+    let mut builder = Builder::new();
+    builder.push_str("abcdefg");
+    builder.push('ƒ');
+    assert!(builder.is_heap());
+    assert_eq!(builder.finish(), "abcdefgƒ");
+}
+
+#[test]
+fn test_with_capacity() {
+    assert!(!Builder::with_capacity(8).is_heap());
+    let builder = Builder::with_capacity(9);
+    assert!(builder.is_heap());
+    assert!(builder.is_empty());
+    assert_eq!(builder.finish(), "");
+}
+
+#[test]
+fn test_spill_leaves_room() {
+    let mut builder = Builder::new();
+    builder.push_str("abcdefgh");
+    builder.push('i');
+    assert!(builder.is_heap());
+    let buffer = builder.as_str().as_ptr();
+    builder.push_str("jklmnop");
+    assert_eq!(builder.as_str().as_ptr(), buffer);
+}
+
+#[test]
+fn test_finish_reuses_exact_allocation() {
+    // Reserving the final length lets `BoxedStr` take the buffer as-is
+    let mut builder = Builder::with_capacity(12);
+    builder.push_str("abcdefgh");
+    builder.push_str("ijkl");
+    let buffer = builder.as_str().as_ptr();
+    let s = builder.finish();
+    assert_eq!(s, "abcdefghijkl");
+    assert_eq!(s.as_ptr(), buffer);
+}
+
+#[test]
+fn test_finish_hands_over_buffer() {
+    let mut builder = KStringBuilder::<OwnedStr, 8>::new();
+    builder.push_str("abcd");
+    builder.push_str("efghijkl");
+    builder.push_str("mnop");
+    let buffer = builder.as_str().as_ptr();
+    let s = builder.finish();
+    assert_eq!(s, "abcdefghijklmnop");
+    assert_eq!(s.as_ptr(), buffer);
+}
+
+/// Backend that keeps whatever `String` it is given
+#[derive(Clone, Debug)]
+struct OwnedStr(String);
+
+impl HeapStr for OwnedStr {
+    fn from_str(other: &str) -> Self {
+        Self(other.to_owned())
+    }
+    fn from_string(other: String) -> Self {
+        Self(other)
+    }
+    fn from_boxed_str(other: BoxedStr) -> Self {
+        Self(other.into())
+    }
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+}
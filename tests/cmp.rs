@@ -0,0 +1,116 @@
+use std::borrow::Cow;
+use std::fmt::Debug;
+
+use kstring::KString;
+use kstring::KStringCow;
+use kstring::KStringRef;
+use kstring::StackString;
+
+/// Build a value of the named type from a `&str`
+macro_rules! value {
+    (str, $s:expr) => {
+        *$s
+    };
+    (ref_str, $s:expr) => {
+        $s
+    };
+    (String, $s:expr) => {
+        String::from($s)
+    };
+    (Box, $s:expr) => {
+        Box::<str>::from($s)
+    };
+    (Cow, $s:expr) => {
+        Cow::<str>::Borrowed($s)
+    };
+    (KString, $s:expr) => {
+        KString::from_ref($s)
+    };
+    (KStringCow, $s:expr) => {
+        KStringCow::from_ref($s)
+    };
+    (KStringRef, $s:expr) => {
+        KStringRef::from_ref($s)
+    };
+    (StackString, $s:expr) => {
+        StackString::<255>::try_new($s).unwrap()
+    };
+}
+
+/// Compare every `lhs` type against every `rhs` type
+macro_rules! check_matrix {
+    ($a:expr, $b:expr; [$($lhs:ident)*] x $rhs:tt) => {
+        $(
+            check_matrix!(@row $a, $b, $lhs, $rhs);
+        )*
+    };
+    (@row $a:expr, $b:expr, $lhs:ident, [$($rhs:ident)*]) => {
+        $(
+            check(
+                &value!($lhs, $a),
+                &value!($rhs, $b),
+                $a,
+                $b,
+                concat!(stringify!($lhs), " vs ", stringify!($rhs)),
+            );
+        )*
+    };
+}
+
+fn check<L, R>(lhs: &L, rhs: &R, a: &str, b: &str, case: &str)
+where
+    L: PartialEq<R> + PartialOrd<R> + Debug + ?Sized,
+    R: Debug + ?Sized,
+{
+    assert_eq!(lhs == rhs, a == b, "{case}: {lhs:?} == {rhs:?}");
+    assert_eq!(lhs != rhs, a != b, "{case}: {lhs:?} != {rhs:?}");
+    assert_eq!(
+        lhs.partial_cmp(rhs),
+        a.partial_cmp(b),
+        "{case}: {lhs:?} <=> {rhs:?}"
+    );
+    assert_eq!(lhs < rhs, a < b, "{case}: {lhs:?} < {rhs:?}");
+    assert_eq!(lhs <= rhs, a <= b, "{case}: {lhs:?} <= {rhs:?}");
+    assert_eq!(lhs > rhs, a > b, "{case}: {lhs:?} > {rhs:?}");
+    assert_eq!(lhs >= rhs, a >= b, "{case}: {lhs:?} >= {rhs:?}");
+}
+
+fn check_all(a: &str, b: &str) {
+    check_matrix!(
+        a, b;
+        [KString KStringCow KStringRef StackString]
+        x [str ref_str String Box Cow KString KStringCow KStringRef StackString]
+    );
+    check_matrix!(
+        a, b;
+        [str ref_str String Box Cow]
+        x [KString KStringCow KStringRef StackString]
+    );
+}
+
+#[test]
+fn test_examples() {
+    for (a, b) in [
+        ("", ""),
+        ("", "a"),
+        ("a", "b"),
+        ("key", "key"),
+        ("key", "keys"),
+        ("ƒ", "z"),
+        ("a string too long to inline", "a string too long to inline"),
+        ("a string too long to inline", "a"),
+    ] {
+        check_all(a, b);
+        check_all(b, a);
+    }
+}
+
+proptest::proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]  // See https://github.com/AltSysrq/proptest/issues/253
+    fn matches_str(a in "\\PC{0,16}", b in "\\PC{0,16}") {
+        check_all(&a, &b);
+        check_all(&a, &a);
+        check_all(&a, &format!("{a}{b}"));
+    }
+}
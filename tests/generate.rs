@@ -0,0 +1,160 @@
+#![cfg(any(feature = "arbitrary", feature = "quickcheck", feature = "proptest"))]
+
+use kstring::KString;
+use kstring::KStringCow;
+use kstring::StackString;
+use kstring::StorageKind;
+
+const N: usize = KString::INLINE_CAPACITY;
+
+/// Assert every storage variant and both sides of the inline capacity were generated
+fn assert_coverage(values: &[KString]) {
+    for kind in [StorageKind::Static, StorageKind::Inline, StorageKind::Heap] {
+        assert!(values.iter().any(|s| s.storage() == kind), "{kind:?}");
+    }
+    assert!(values.iter().any(|s| s.len() == N));
+    assert!(values.iter().any(|s| s.len() == N + 1));
+    for s in values.iter().filter(|s| s.storage() != StorageKind::Static) {
+        assert_eq!(s.storage() == StorageKind::Inline, s.len() <= N, "{s:?}");
+    }
+}
+
+fn assert_stack_coverage(values: &[StackString<8>]) {
+    assert!(values.iter().any(|s| s.len() == 8));
+    assert!(values.iter().any(|s| s.len() < 8));
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary {
+    use kstring::KStringRef;
+
+    use super::*;
+
+    fn generate<'a, T: ::arbitrary::Arbitrary<'a>>(data: &'a [u8]) -> Vec<T> {
+        data.chunks(64)
+            .filter_map(|chunk| ::arbitrary::Unstructured::new(chunk).arbitrary().ok())
+            .collect()
+    }
+
+    fn data() -> Vec<u8> {
+        // Deterministic but varied input
+        (0..64 * 256_u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+            .collect()
+    }
+
+    #[test]
+    fn test_kstring() {
+        let data = data();
+        assert_coverage(&generate::<KString>(&data));
+    }
+
+    #[test]
+    fn test_kstring_cow() {
+        let data = data();
+        let values = generate::<KStringCow<'_>>(&data);
+        assert!(values.iter().any(|s| s.storage() == StorageKind::Borrowed));
+        assert!(values.iter().any(|s| s.storage() == StorageKind::Heap));
+    }
+
+    #[test]
+    fn test_kstring_ref() {
+        let data = data();
+        let values = generate::<KStringRef<'_>>(&data);
+        assert!(values.iter().any(|s| s.storage() == StorageKind::Static));
+        assert!(values.iter().any(|s| s.storage() == StorageKind::Borrowed));
+    }
+
+    #[test]
+    fn test_stack_string() {
+        let data = data();
+        assert_stack_coverage(&generate::<StackString<8>>(&data));
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod quickcheck {
+    use ::quickcheck::Arbitrary;
+    use ::quickcheck::Gen;
+
+    use super::*;
+
+    fn generate<T: Arbitrary>() -> Vec<T> {
+        let mut g = Gen::new(64);
+        (0..256).map(|_| T::arbitrary(&mut g)).collect()
+    }
+
+    #[test]
+    fn test_kstring() {
+        assert_coverage(&generate::<KString>());
+    }
+
+    #[test]
+    fn test_kstring_cow() {
+        let values = generate::<KStringCow<'static>>();
+        assert!(values.iter().any(|s| s.storage() == StorageKind::Borrowed));
+        assert!(values.iter().any(|s| s.storage() == StorageKind::Heap));
+    }
+
+    #[test]
+    fn test_stack_string() {
+        assert_stack_coverage(&generate::<StackString<8>>());
+    }
+
+    #[test]
+    fn test_shrink() {
+        let s = KString::from_ref("a string that lives on the heap");
+        assert!(s.shrink().any(|shrunk| shrunk.len() < s.len()));
+        let s = StackString::<8>::try_new("abcdefgh").unwrap();
+        assert!(s.shrink().any(|shrunk| shrunk.len() < s.len()));
+    }
+
+    ::quickcheck::quickcheck! {
+        fn prop_from_ref_eq(s: KString) -> bool {
+            KString::from_ref(&s) == s
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest {
+    use ::proptest::strategy::ValueTree as _;
+    use ::proptest::test_runner::TestRunner;
+
+    use super::*;
+
+    fn generate<S: ::proptest::strategy::Strategy>(strategy: S) -> Vec<S::Value> {
+        let mut runner = TestRunner::deterministic();
+        (0..256)
+            .map(|_| strategy.new_tree(&mut runner).unwrap().current())
+            .collect()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // See https://github.com/AltSysrq/proptest/issues/253
+    fn test_kstring() {
+        assert_coverage(&generate(kstring::strategy::kstring()));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // See https://github.com/AltSysrq/proptest/issues/253
+    fn test_kstring_cow() {
+        let values: Vec<KStringCow<'static>> = generate(kstring::strategy::kstring_cow());
+        assert!(values.iter().any(|s| s.storage() == StorageKind::Borrowed));
+        assert!(values.iter().any(|s| s.storage() == StorageKind::Heap));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // See https://github.com/AltSysrq/proptest/issues/253
+    fn test_stack_string() {
+        assert_stack_coverage(&generate(kstring::strategy::stack_string()));
+    }
+
+    ::proptest::proptest! {
+        #[test]
+        #[cfg_attr(miri, ignore)] // See https://github.com/AltSysrq/proptest/issues/253
+        fn from_ref_eq(s: KString) {
+            ::proptest::prop_assert_eq!(KString::from_ref(&s), s);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+#![cfg(any(feature = "get-size", feature = "deepsize", feature = "datasize"))]
+
+use kstring::backend::HeapStr as _;
+use kstring::KString;
+use kstring::KStringCow;
+
+fn long() -> String {
+    "key".repeat(KString::INLINE_CAPACITY)
+}
+
+/// Size of an `Arc<str>` or `Rc<str>` allocation, with its two reference counts
+fn shared_allocation(s: &str) -> usize {
+    let (layout, _) = std::alloc::Layout::new::<[usize; 2]>()
+        .extend(std::alloc::Layout::for_value(s))
+        .unwrap();
+    layout.pad_to_align().size()
+}
+
+#[test]
+fn test_backend_heap_size() {
+    let long = long();
+
+    let boxed = kstring::backend::BoxedStr::from_str(&long);
+    assert_eq!(boxed.heap_size(), long.len());
+
+    let arc = kstring::backend::ArcStr::from_str(&long);
+    let single = arc.heap_size();
+    assert_eq!(single, shared_allocation(&long));
+    let shared = arc.clone();
+    assert_eq!(arc.heap_size(), single / 2);
+    assert_eq!(shared.heap_size(), single / 2);
+}
+
+#[cfg(feature = "get-size")]
+#[test]
+fn test_get_size() {
+    use get_size::GetSize as _;
+
+    let long = long();
+    assert_eq!(KString::from_static("key").get_heap_size(), 0);
+    assert_eq!(KString::from_ref("key").get_heap_size(), 0);
+    assert_eq!(KString::from_static(long.clone().leak()).get_heap_size(), 0);
+    assert!(long.len() <= KString::from_ref(&long).get_heap_size());
+
+    assert_eq!(KStringCow::from_ref(&long).get_heap_size(), 0);
+    assert!(long.len() <= KStringCow::from_string(long.clone()).get_heap_size());
+    assert_eq!(kstring::KStringRef::from_ref(&long).get_heap_size(), 0);
+    assert_eq!(kstring::StackString::<16>::new("key").get_heap_size(), 0);
+
+    // Shared allocations are split between their handles
+    let arc = kstring::KStringBase::<kstring::backend::ArcStr>::from_ref(&long);
+    assert_eq!(arc.get_heap_size(), shared_allocation(&long));
+    let shared = [arc.clone(), arc.clone(), arc];
+    for s in &shared {
+        assert_eq!(s.get_heap_size(), shared_allocation(&long) / 3);
+    }
+}
+
+#[cfg(feature = "datasize")]
+#[test]
+fn test_datasize() {
+    use datasize::data_size;
+
+    let long = long();
+    assert_eq!(data_size(&KString::from_static("key")), 0);
+    assert_eq!(data_size(&KString::from_ref("key")), 0);
+    assert_eq!(data_size(&KString::from_static(long.clone().leak())), 0);
+    assert_eq!(
+        data_size(&kstring::KStringBase::<kstring::backend::BoxedStr>::from_ref(&long)),
+        long.len()
+    );
+
+    assert_eq!(data_size(&KStringCow::from_ref(&long)), 0);
+    assert_eq!(
+        data_size(
+            &kstring::KStringCowBase::<kstring::backend::BoxedStr>::from_string(long.clone())
+        ),
+        long.len()
+    );
+    assert_eq!(data_size(&kstring::KStringRef::from_ref(&long)), 0);
+    assert_eq!(data_size(&kstring::StackString::<16>::new("key")), 0);
+
+    // Shared allocations are split between their handles
+    let arc = kstring::KStringBase::<kstring::backend::ArcStr>::from_ref(&long);
+    assert_eq!(data_size(&arc), shared_allocation(&long));
+    let shared = [arc.clone(), arc];
+    for s in &shared {
+        assert_eq!(data_size(s), shared_allocation(&long) / 2);
+    }
+}
+
+#[cfg(feature = "deepsize")]
+#[test]
+fn test_deepsize() {
+    use deepsize::DeepSizeOf as _;
+
+    type BoxedKString = kstring::KStringBase<kstring::backend::BoxedStr>;
+    type ArcKString = kstring::KStringBase<kstring::backend::ArcStr>;
+    type RcKString = kstring::KStringBase<kstring::backend::RcStr>;
+
+    let long = long();
+    assert_eq!(
+        BoxedKString::from_ref("key").deep_size_of(),
+        size_of::<BoxedKString>()
+    );
+    assert_eq!(
+        BoxedKString::from_static("key").deep_size_of(),
+        size_of::<BoxedKString>()
+    );
+    assert_eq!(
+        BoxedKString::from_ref(&long).deep_size_of(),
+        size_of::<BoxedKString>() + long.len()
+    );
+    assert_eq!(
+        KStringCow::from_ref(&long).deep_size_of(),
+        size_of::<KStringCow<'_>>()
+    );
+    assert_eq!(
+        kstring::KStringCowBase::<kstring::backend::BoxedStr>::from_string(long.clone())
+            .deep_size_of(),
+        size_of::<kstring::KStringCowBase<'_, kstring::backend::BoxedStr>>() + long.len()
+    );
+
+    // Like `deepsize`'s own `ArcKString` and `RcKString`, shared strings are counted once
+    let arc = ArcKString::from_ref(&long);
+    let keys = vec![
+        arc.clone(),
+        arc.clone(),
+        arc,
+        ArcKString::from_static("key"),
+    ];
+    assert_eq!(
+        keys.deep_size_of(),
+        size_of::<Vec<ArcKString>>() + keys.capacity() * size_of::<ArcKString>() + long.len()
+    );
+    let keys = vec![ArcKString::from_ref(&long), ArcKString::from_ref(&long)];
+    assert_eq!(
+        keys.deep_size_of(),
+        size_of::<Vec<ArcKString>>() + keys.capacity() * size_of::<ArcKString>() + 2 * long.len()
+    );
+    let rc = RcKString::from_ref(&long);
+    let keys = vec![rc.clone(), rc];
+    assert_eq!(
+        keys.deep_size_of(),
+        size_of::<Vec<RcKString>>() + keys.capacity() * size_of::<RcKString>() + long.len()
+    );
+}
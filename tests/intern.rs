@@ -0,0 +1,96 @@
+#![cfg(feature = "std")]
+
+use kstring::backend::ArcStr;
+use kstring::backend::RcStr;
+use kstring::intern::InternStats;
+use kstring::intern::Interner;
+use kstring::intern::LocalInterner;
+use kstring::KString;
+use kstring::KStringBase;
+
+const LONG: &str = "0123456789012345678901234567890123456789";
+
+#[test]
+fn test_shares_allocation() {
+    let interner = Interner::new();
+    let first = interner.intern(LONG);
+    let second = interner.intern(&String::from(LONG));
+    assert_eq!(first, LONG);
+    assert_eq!(first.as_ptr(), second.as_ptr());
+    assert_eq!(interner.len(), 1);
+}
+
+#[test]
+fn test_inline_bypasses_table() {
+    let interner = Interner::new();
+    let key = interner.intern("id");
+    assert_eq!(key, "id");
+    assert!(interner.is_empty());
+}
+
+#[test]
+fn test_purge_keeps_live_entries() {
+    let interner = Interner::new();
+    let live = interner.intern(LONG);
+    let dead = interner.intern("9876543210987654321098765432109876543210");
+    drop(dead);
+    assert_eq!(interner.purge(), 1);
+    assert_eq!(interner.len(), 1);
+    assert_eq!(interner.intern(LONG).as_ptr(), live.as_ptr());
+}
+
+#[test]
+fn test_global_across_threads() {
+    let handles = (0..4)
+        .map(|_| std::thread::spawn(|| KString::intern(LONG).as_ptr() as usize))
+        .collect::<Vec<_>>();
+    let ptrs = handles
+        .into_iter()
+        .map(|h| h.join().unwrap())
+        .collect::<Vec<_>>();
+    assert!(ptrs.windows(2).all(|w| w[0] == w[1]));
+}
+
+#[test]
+fn test_intern_any_capacity() {
+    let first: KStringBase<ArcStr, 40> = KStringBase::<RcStr, 40>::intern(LONG);
+    assert_eq!(first.storage(), kstring::StorageKind::Inline);
+    let second: KStringBase<ArcStr, 8> = KStringBase::<RcStr, 8>::intern(LONG);
+    assert_eq!(second.storage(), kstring::StorageKind::Heap);
+    assert_eq!(second.as_ptr(), KString::intern(LONG).as_ptr());
+}
+
+#[test]
+fn test_local_stats() {
+    let interner = LocalInterner::new();
+    let first = interner.intern(LONG);
+    let second = interner.intern(LONG);
+    let _ = interner.intern("id");
+    assert_eq!(first.as_ptr(), second.as_ptr());
+    let stats = interner.stats();
+    assert_eq!(stats.hits, 1);
+    assert_eq!(stats.misses, 1);
+
+    interner.reset_stats();
+    assert_eq!(interner.stats(), InternStats::default());
+}
+
+#[test]
+fn test_local_clear() {
+    let interner = LocalInterner::new();
+    let before = interner.intern(LONG);
+    interner.clear();
+    interner.shrink_to_fit();
+    assert!(interner.is_empty());
+    let after = interner.intern(LONG);
+    assert_eq!(before, after);
+    assert_ne!(before.as_ptr(), after.as_ptr());
+}
+
+#[test]
+fn test_thread_local() {
+    let first: KStringBase<RcStr> = KString::intern_local(LONG);
+    let second = KString::intern_local(LONG);
+    assert_eq!(first.as_ptr(), second.as_ptr());
+    LocalInterner::with_thread_local(|interner| assert_eq!(interner.len(), 1));
+}
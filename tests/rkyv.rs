@@ -0,0 +1,98 @@
+#![cfg(feature = "rkyv")]
+
+use std::collections::BTreeMap;
+use std::hash::BuildHasher as _;
+
+use kstring::KString;
+use kstring::KStringCow;
+use kstring::StackString;
+use rkyv::rancor::Error;
+use rkyv::string::ArchivedString;
+
+const LONG: &str = "a-long-key-that-would-not-fit-inline";
+
+#[test]
+fn test_roundtrip() {
+    let keys = vec![
+        KString::from_static("static"),
+        KString::from_ref("inline"),
+        KString::from_ref(LONG),
+        KString::new(),
+    ];
+    let bytes = rkyv::to_bytes::<Error>(&keys).unwrap();
+    let archived = rkyv::access::<rkyv::Archived<Vec<KString>>, Error>(&bytes).unwrap();
+    assert_eq!(archived.as_slice(), keys.as_slice());
+
+    let deserialized: Vec<KString> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(deserialized, keys);
+    assert_eq!(deserialized[1].storage(), kstring::StorageKind::Inline);
+}
+
+#[test]
+fn test_same_archive_as_string() {
+    let key = KString::from_ref(LONG);
+    let bytes = rkyv::to_bytes::<Error>(&key).unwrap();
+    let string_bytes = rkyv::to_bytes::<Error>(&String::from(LONG)).unwrap();
+    assert_eq!(bytes.as_slice(), string_bytes.as_slice());
+}
+
+#[test]
+fn test_short_strings_stay_inline() {
+    let bytes = rkyv::to_bytes::<Error>(&KString::from_ref("key")).unwrap();
+    assert_eq!(bytes.len(), size_of::<ArchivedString>());
+}
+
+#[test]
+fn test_lookup_without_deserializing() {
+    let index: BTreeMap<KString, u32> = [
+        (KString::from_ref("first"), 1),
+        (KString::from_ref(LONG), 2),
+    ]
+    .into_iter()
+    .collect();
+    let bytes = rkyv::to_bytes::<Error>(&index).unwrap();
+    let archived = rkyv::access::<rkyv::Archived<BTreeMap<KString, u32>>, Error>(&bytes).unwrap();
+    assert_eq!(archived.get("first").map(|v| v.to_native()), Some(1));
+    assert_eq!(archived.get(LONG).map(|v| v.to_native()), Some(2));
+    assert_eq!(archived.get("missing"), None);
+}
+
+#[test]
+fn test_hash_matches_str() {
+    let bytes = rkyv::to_bytes::<Error>(&KString::from_ref(LONG)).unwrap();
+    let archived = rkyv::access::<ArchivedString, Error>(&bytes).unwrap();
+    let state = std::hash::RandomState::new();
+    assert_eq!(state.hash_one(archived), state.hash_one(LONG));
+    assert_eq!(
+        state.hash_one(archived),
+        state.hash_one(KString::from_ref(LONG))
+    );
+}
+
+#[test]
+fn test_cow() {
+    let cow = KStringCow::from_ref(LONG);
+    let bytes = rkyv::to_bytes::<Error>(&cow).unwrap();
+    let archived = rkyv::access::<ArchivedString, Error>(&bytes).unwrap();
+    assert_eq!(*archived, cow);
+    let deserialized: KStringCow<'_> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(deserialized, LONG);
+}
+
+#[test]
+fn test_stack_string() {
+    let stack = StackString::<16>::new("key");
+    let bytes = rkyv::to_bytes::<Error>(&stack).unwrap();
+    let archived = rkyv::access::<ArchivedString, Error>(&bytes).unwrap();
+    assert_eq!(*archived, stack);
+    let deserialized: StackString<16> = rkyv::deserialize::<_, Error>(archived).unwrap();
+    assert_eq!(deserialized, "key");
+
+    let bytes = rkyv::to_bytes::<Error>(&KString::from_ref(LONG)).unwrap();
+    let archived = rkyv::access::<ArchivedString, Error>(&bytes).unwrap();
+    let err = rkyv::deserialize::<StackString<16>, Error>(archived).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!("string of {} bytes exceeds capacity of 16", LONG.len())
+    );
+}
@@ -0,0 +1,185 @@
+#![cfg(feature = "serde")]
+
+use kstring::KString;
+use kstring::KStringCow;
+use kstring::StorageKind;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Borrowed<'a> {
+    #[serde(borrow, with = "kstring::serde::borrow")]
+    key: KStringCow<'a>,
+}
+
+// Deriving without `#[serde(borrow)]` keeps working with any lifetime
+#[derive(serde::Deserialize)]
+struct Owned<'a> {
+    key: KStringCow<'a>,
+    static_key: KStringCow<'static>,
+}
+
+#[test]
+fn test_cow_owns_by_default() {
+    let json = r#""a-long-key-that-would-not-fit-inline""#;
+    let cow: KStringCow<'_> = serde_json::from_str(json).unwrap();
+    assert_eq!(cow, "a-long-key-that-would-not-fit-inline");
+    assert_eq!(cow.storage(), StorageKind::Heap);
+
+    let owned: Owned<'_> = serde_json::from_str(r#"{"key": "key", "static_key": "key"}"#).unwrap();
+    assert_eq!(owned.key.storage(), StorageKind::Inline);
+    assert_eq!(owned.static_key.storage(), StorageKind::Inline);
+}
+
+#[test]
+fn test_cow_deserialize_owned() {
+    fn from_reader<T: serde::de::DeserializeOwned>(json: &[u8]) -> T {
+        serde_json::from_reader(json).unwrap()
+    }
+
+    let cow: KStringCow<'static> = from_reader(br#""key""#);
+    assert_eq!(cow, "key");
+}
+
+#[test]
+fn test_cow_borrows_unescaped() {
+    let json = r#"{"key": "a-long-key-that-would-not-fit-inline"}"#;
+    let borrowed: Borrowed<'_> = serde_json::from_str(json).unwrap();
+    assert_eq!(borrowed.key, "a-long-key-that-would-not-fit-inline");
+    assert_eq!(borrowed.key.storage(), StorageKind::Borrowed);
+
+    let mut deserializer = serde_json::Deserializer::from_str(r#""key""#);
+    let cow: KStringCow<'_> = kstring::serde::borrow::deserialize(&mut deserializer).unwrap();
+    assert_eq!(cow, "key");
+    assert_eq!(cow.storage(), StorageKind::Borrowed);
+}
+
+#[test]
+fn test_cow_borrow_owns_escaped() {
+    let json = r#"{"key": "a-long-key-that-\"needs\"-unescaping"}"#;
+    let borrowed: Borrowed<'_> = serde_json::from_str(json).unwrap();
+    assert_eq!(borrowed.key, r#"a-long-key-that-"needs"-unescaping"#);
+    assert_eq!(borrowed.key.storage(), StorageKind::Heap);
+
+    let borrowed: Borrowed<'_> = serde_json::from_str(r#"{"key": "\"key\""}"#).unwrap();
+    assert_eq!(borrowed.key, r#""key""#);
+    assert_eq!(borrowed.key.storage(), StorageKind::Inline);
+}
+
+#[test]
+fn test_cow_borrow_roundtrip() {
+    let borrowed = Borrowed {
+        key: KStringCow::from_ref("key"),
+    };
+    let json = serde_json::to_string(&borrowed).unwrap();
+    assert_eq!(json, r#"{"key":"key"}"#);
+    let borrowed: Borrowed<'_> = serde_json::from_str(&json).unwrap();
+    assert_eq!(borrowed.key, "key");
+}
+
+#[test]
+fn test_roundtrip() {
+    for s in ["", "key", "a-long-key-that-would-not-fit-inline"] {
+        let json = serde_json::to_string(&KString::from_ref(s)).unwrap();
+        let kstring: KString = serde_json::from_str(&json).unwrap();
+        assert_eq!(kstring, s);
+        let cow: KStringCow<'_> = serde_json::from_str(&json).unwrap();
+        assert_eq!(cow, s);
+    }
+}
+
+#[test]
+fn test_inline_from_borrowed_buffer() {
+    let bytes = bincode1::serialize("key").unwrap();
+    let kstring: KString = bincode1::deserialize(&bytes).unwrap();
+    assert_eq!(kstring, "key");
+    assert_eq!(kstring.storage(), StorageKind::Inline);
+}
+
+#[test]
+fn test_deserialize_in_place() {
+    use serde::Deserialize as _;
+
+    let long = "a-long-key-that-would-not-fit-inline";
+    let mut place = KString::new();
+
+    let mut deserializer = serde_json::Deserializer::from_str(r#""key""#);
+    KString::deserialize_in_place(&mut deserializer, &mut place).unwrap();
+    assert_eq!(place, "key");
+    assert_eq!(place.storage(), StorageKind::Inline);
+
+    let json = serde_json::to_string(long).unwrap();
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    KString::deserialize_in_place(&mut deserializer, &mut place).unwrap();
+    assert_eq!(place, long);
+    assert_eq!(place.storage(), StorageKind::Heap);
+
+    let mut deserializer = serde_json::Deserializer::from_str("1");
+    assert!(KString::deserialize_in_place(&mut deserializer, &mut place).is_err());
+    assert_eq!(place, long);
+}
+
+#[test]
+fn test_deserialize_in_place_keeps_shared_storage() {
+    use serde::Deserialize as _;
+
+    type ArcKString = kstring::KStringBase<kstring::backend::ArcStr>;
+
+    let long = "a-long-key-that-would-not-fit-inline";
+    let original = ArcKString::from_ref(long);
+    let mut place = original.clone();
+    let json = serde_json::to_string(long).unwrap();
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    ArcKString::deserialize_in_place(&mut deserializer, &mut place).unwrap();
+    assert_eq!(place, long);
+    assert_eq!(place.as_str().as_ptr(), original.as_str().as_ptr());
+}
+
+#[test]
+fn test_invalid_utf8_bytes() {
+    let bytes = bincode1::serialize(&[0xff_u8, 0xfe][..]).unwrap();
+    assert!(bincode1::deserialize::<KString>(&bytes).is_err());
+}
+
+#[test]
+fn test_stack_string_roundtrip() {
+    let stack = kstring::StackString::<8>::new("key");
+    let json = serde_json::to_string(&stack).unwrap();
+    assert_eq!(json, r#""key""#);
+    let stack: kstring::StackString<8> = serde_json::from_str(&json).unwrap();
+    assert_eq!(stack, "key");
+
+    let stack: kstring::StackString<8> = serde_json::from_str(r#""12345678""#).unwrap();
+    assert_eq!(stack, "12345678");
+}
+
+#[test]
+fn test_stack_string_too_long() {
+    let err = serde_json::from_str::<kstring::StackString<8>>(r#""123456789""#).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid length 9, expected a string of at most 8 bytes at line 1 column 11"
+    );
+
+    let bytes = bincode1::serialize(&b"123456789"[..]).unwrap();
+    let err = bincode1::deserialize::<kstring::StackString<8>>(&bytes).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid length 9, expected a string of at most 8 bytes"
+    );
+}
+
+#[test]
+fn test_stack_string_bytes() {
+    use serde::de::value::BytesDeserializer;
+    use serde::de::value::Error;
+    use serde::Deserialize as _;
+
+    let stack = kstring::StackString::<8>::deserialize(BytesDeserializer::<Error>::new(b"key"));
+    assert_eq!(stack.unwrap(), "key");
+
+    let err = kstring::StackString::<8>::deserialize(BytesDeserializer::<Error>::new(b"\xff"))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: byte array, expected a string of at most 8 bytes"
+    );
+}
@@ -0,0 +1,136 @@
+use std::fmt::Write as _;
+
+use kstring::StackString;
+
+type Stack = StackString<8>;
+
+#[test]
+fn test_push_overflow_is_untouched() {
+    let mut s = Stack::try_new("abcdefg").unwrap();
+    assert!(s.try_push('ƒ').is_err());
+    assert!(s.try_push_str("hi").is_err());
+    assert_eq!(s, "abcdefg");
+    assert!(s.try_push('h').is_ok());
+    assert_eq!(s, "abcdefgh");
+}
+
+#[test]
+fn test_insert_remove() {
+    let mut s = Stack::try_new("ac").unwrap();
+    s.insert(1, 'ƒ').unwrap();
+    assert_eq!(s, "aƒc");
+    assert_eq!(s.remove(1), 'ƒ');
+    assert_eq!(s, "ac");
+    assert_eq!(s.pop(), Some('c'));
+    assert_eq!(s.pop(), Some('a'));
+    assert_eq!(s.pop(), None);
+}
+
+#[test]
+#[should_panic = "is not a char boundary"]
+fn test_insert_not_char_boundary() {
+    let mut s = Stack::try_new("ƒ").unwrap();
+    let _ = s.insert(1, 'a');
+}
+
+#[test]
+fn test_drain() {
+    let mut s = Stack::try_new("aƒbc").unwrap();
+    let mut drain = s.drain(1..4);
+    assert_eq!(drain.as_str(), "ƒb");
+    assert_eq!(drain.next_back(), Some('b'));
+    assert_eq!(drain.next(), Some('ƒ'));
+    assert_eq!(drain.next(), None);
+    assert_eq!(s, "ac");
+
+    // Removed even when not consumed
+    let _ = s.drain(..1);
+    assert_eq!(s, "c");
+}
+
+#[test]
+fn test_write() {
+    let mut s = Stack::EMPTY;
+    write!(s, "{}-{}", 1, 2).unwrap();
+    assert_eq!(s, "1-2");
+    assert!(write!(s, "{}", 123_456).is_err());
+}
+
+proptest::proptest! {
+    #[test]
+    #[cfg_attr(miri, ignore)]  // See https://github.com/AltSysrq/proptest/issues/253
+    fn replace_range_matches_string(s in "\\PC{0,8}", with in "\\PC{0,8}", a: usize, b: usize) {
+        let mut expected = s.clone();
+        let mut actual = StackString::<32>::try_new(&s).unwrap();
+        let start = a % (s.len() + 1);
+        let end = start + b % (s.len() - start + 1);
+        if s.is_char_boundary(start) && s.is_char_boundary(end) {
+            expected.replace_range(start..end, &with);
+            let result = actual.replace_range(start..end, &with);
+            if expected.len() <= 32 {
+                proptest::prop_assert!(result.is_ok());
+                proptest::prop_assert_eq!(actual.as_str(), expected.as_str());
+            } else {
+                proptest::prop_assert!(result.is_err());
+                proptest::prop_assert_eq!(actual.as_str(), s.as_str());
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]  // See https://github.com/AltSysrq/proptest/issues/253
+    fn retain_matches_string(s in "\\PC{0,8}") {
+        let mut expected = s.clone();
+        let mut actual = StackString::<32>::try_new(&s).unwrap();
+        expected.retain(|c| c.is_ascii());
+        actual.retain(|c| c.is_ascii());
+        proptest::prop_assert_eq!(actual.as_str(), expected.as_str());
+    }
+}
+
+#[test]
+fn test_try_from() {
+    assert_eq!(Stack::try_from("abc").unwrap(), "abc");
+    assert_eq!(Stack::try_from(String::from("abc")).unwrap(), "abc");
+    assert_eq!(StackString::<2>::try_from('ƒ').unwrap(), "ƒ");
+
+    let err = StackString::<1>::try_from('ƒ').unwrap_err();
+    assert_eq!((err.requested(), err.capacity()), (2, 1));
+    let err = Stack::try_from("abcdefghi").unwrap_err();
+    assert_eq!((err.requested(), err.capacity()), (9, 8));
+    #[cfg(any(feature = "std", feature = "rkyv"))]
+    let _: &dyn std::error::Error = &err;
+}
+
+#[test]
+fn test_mutation_error() {
+    let mut s = Stack::try_new("abcdefg").unwrap();
+    let err = s.try_push_str("hij").unwrap_err();
+    assert_eq!((err.requested(), err.capacity()), (10, 8));
+    let err = s.replace_range(..1, "ƒƒ").unwrap_err();
+    assert_eq!((err.requested(), err.capacity()), (10, 8));
+}
+
+#[test]
+fn test_from_utf8() {
+    assert_eq!(Stack::from_utf8("aƒ".as_bytes()).unwrap(), "aƒ");
+    assert_eq!(
+        Stack::from_utf8(b"abcdefghi"),
+        Err(kstring::FromUtf8Error::Capacity(
+            Stack::try_from("abcdefghi").unwrap_err()
+        ))
+    );
+    assert!(matches!(
+        Stack::from_utf8(b"\xc5"),
+        Err(kstring::FromUtf8Error::Utf8(_))
+    ));
+}
+
+#[test]
+fn test_from_utf8_lossy() {
+    assert_eq!(Stack::from_utf8_lossy(b"a\xffb").unwrap(), "a\u{FFFD}b");
+    // Truncated multi-byte sequences collapse into one replacement
+    assert_eq!(Stack::from_utf8_lossy(b"a\xe2\x82").unwrap(), "a\u{FFFD}");
+    let err = Stack::from_utf8_lossy(b"\xff\xff\xff").unwrap_err();
+    assert_eq!((err.requested(), err.capacity()), (9, 8));
+}
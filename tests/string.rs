@@ -39,3 +39,136 @@ proptest::proptest! {
         check_props(s.as_str(), uut)?;
     }
 }
+
+#[test]
+fn test_inline_capacity() {
+    type Aligned = kstring::KStringAligned<kstring::backend::BoxedStr>;
+    type MaxInline = kstring::KStringMaxInline<kstring::backend::BoxedStr>;
+    type Wide = kstring::KStringBase<kstring::backend::BoxedStr, 40>;
+
+    assert_eq!(size_of::<Aligned>(), size_of::<String>());
+    assert_eq!(size_of::<MaxInline>(), size_of::<String>());
+    assert_eq!(Aligned::INLINE_CAPACITY, size_of::<Box<str>>() - 1);
+    assert_eq!(MaxInline::INLINE_CAPACITY, size_of::<String>() - 2);
+
+    let longest = "0".repeat(Wide::INLINE_CAPACITY);
+    assert!(Wide::try_inline(&longest).is_some());
+    let wide = Wide::from_ref(&longest);
+    assert_eq!(wide, longest.as_str());
+
+    let too_long = "0".repeat(Wide::INLINE_CAPACITY + 1);
+    assert!(Wide::try_inline(&too_long).is_none());
+    let cow = kstring::KStringCowBase::<'_, kstring::backend::BoxedStr, 40>::from(&wide);
+    assert_eq!(cow.into_owned(), wide);
+}
+
+#[test]
+fn test_from_utf8() {
+    let long = "0".repeat(kstring::KString::INLINE_CAPACITY + 1);
+    assert_eq!(kstring::KString::from_utf8(long.as_bytes()).unwrap(), long);
+    assert!(kstring::KString::from_utf8(b"\xff").is_err());
+
+    let lossy = kstring::KString::from_utf8_lossy(b"a\xffb");
+    assert_eq!(lossy, "a\u{FFFD}b");
+}
+
+#[test]
+fn test_const() {
+    const INLINE: kstring::KString = kstring::kstring!("inline");
+    static SINGLETON: kstring::KString = kstring::kstring!(
+        "a string long enough that it can never be stored inline, in any configuration"
+    );
+    const INLINED: Option<kstring::KString> = kstring::KString::try_inline("inline");
+
+    assert_eq!(INLINE, "inline");
+    assert_eq!(INLINED.unwrap(), INLINE);
+    assert!(SINGLETON.starts_with("a string long enough"));
+    assert!(kstring::KString::try_inline(&SINGLETON).is_none());
+}
+
+#[test]
+fn test_kformat() {
+    type Key = kstring::KStringBase<kstring::backend::BoxedStr, 8>;
+
+    let literal = kstring::kformat!("no arguments, so this is never copied");
+    assert_eq!(literal, "no arguments, so this is never copied");
+
+    let inline = Key::from_fmt(format_args!("{}-{}", "ab", 1234));
+    assert_eq!(inline, "ab-1234");
+    assert!(Key::try_inline(&inline).is_some());
+
+    let heap = Key::from_fmt(format_args!("{}-{}", "abcd", 1234));
+    assert_eq!(heap, "abcd-1234");
+}
+
+#[test]
+fn test_storage() {
+    use kstring::StorageKind;
+
+    let long = "0".repeat(kstring::KString::INLINE_CAPACITY + 1);
+    let static_ = kstring::KString::from_static("key");
+    let inline = kstring::KString::from_ref("key");
+    let heap = kstring::KString::from_ref(&long);
+    assert_eq!(static_.storage(), StorageKind::Static);
+    assert_eq!(inline.storage(), StorageKind::Inline);
+    assert_eq!(heap.storage(), StorageKind::Heap);
+    assert_eq!(heap.clone().storage(), StorageKind::Heap);
+    assert_eq!(
+        (static_.heap_bytes(), inline.heap_bytes(), heap.heap_bytes()),
+        (0, 0, long.len())
+    );
+
+    assert_eq!(static_.as_ref().storage(), StorageKind::Static);
+    assert_eq!(heap.as_ref().storage(), StorageKind::Borrowed);
+
+    let cow = kstring::KStringCow::from(&heap);
+    assert_eq!(cow.storage(), StorageKind::Borrowed);
+    assert_eq!(cow.heap_bytes(), 0);
+    let cow = kstring::KStringCow::from(heap);
+    assert_eq!(cow.storage(), StorageKind::Heap);
+    assert_eq!(cow.heap_bytes(), long.len());
+}
+
+#[test]
+fn test_ptr_eq() {
+    type Shared = kstring::KStringBase<kstring::backend::ArcStr>;
+    type Unique = kstring::KStringBase<kstring::backend::BoxedStr>;
+
+    let long = "0".repeat(kstring::KString::INLINE_CAPACITY + 1);
+
+    let shared = Shared::from_ref(&long);
+    assert!(Shared::ptr_eq(&shared, &shared.clone()));
+    assert!(!Shared::ptr_eq(&shared, &Shared::from_ref(&long)));
+    assert_eq!(shared, shared.clone());
+
+    let unique = Unique::from_ref(&long);
+    assert!(!Unique::ptr_eq(&unique, &unique.clone()));
+    assert_eq!(unique, unique.clone());
+
+    let inline = Shared::from_ref("key");
+    assert!(Shared::ptr_eq(&inline, &inline));
+    assert!(!Shared::ptr_eq(&inline, &inline.clone()));
+
+    let static_ = Shared::from_static("key");
+    assert!(Shared::ptr_eq(&static_, &static_.clone()));
+
+    let cow = kstring::KStringCow::from_ref(&long);
+    assert!(kstring::KStringCow::ptr_eq(&cow, &cow.clone()));
+    assert!(kstring::KStringRef::ptr_eq(
+        &cow.as_ref(),
+        &kstring::KStringRef::from_ref(&long)
+    ));
+}
+
+#[test]
+#[cfg_attr(miri, ignore)] // Slow
+#[cfg(not(all(feature = "thin", target_pointer_width = "32")))]
+fn test_large_static() {
+    // Past what fits in 3 bytes, the most the `thin` layout can pack on 32-bit targets
+    let large: &'static str = "0".repeat(1 << 24).leak();
+    let s = kstring::KString::from_static(large);
+    assert_eq!(s.storage(), kstring::StorageKind::Static);
+    assert_eq!(s.len(), large.len());
+    assert_eq!(s.as_str().as_ptr(), large.as_ptr());
+    assert_eq!(s.clone(), large);
+}